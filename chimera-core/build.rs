@@ -10,7 +10,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
     
     tonic_build::configure()
-        .build_server(false)  // We're a client, not a server
+        // We're a client in production, but `testutil::MockBrain` implements
+        // the server trait to serve an in-process mock for retry/backoff tests
+        .build_server(true)
         .compile_protos(
             &[proto_path],
             &[include_dir],
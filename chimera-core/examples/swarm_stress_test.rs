@@ -0,0 +1,166 @@
+//! Swarm Stress Test Harness
+//!
+//! Spins up N simulated phantom workers against a single Brain address to
+//! measure sustainable throughput and latency before real missions are
+//! scheduled. Each worker drives the same `ChimeraClient` path (connect,
+//! retry/backoff, congestion control, rate limiting) so the numbers reflect
+//! what a real swarm would actually see, not a synthetic benchmark.
+//!
+//! Configure via env:
+//! * `CHIMERA_STRESS_WORKERS` - number of simulated workers (default: 10)
+//! * `CHIMERA_STRESS_RPS` - target aggregate requests/sec across all workers (default: 20)
+//! * `CHIMERA_STRESS_DURATION_SECS` - test duration (default: 30)
+//! * `CHIMERA_BRAIN_ADDRESS` - Brain address to hit (same as production)
+//!
+//! Run with: `cargo run --example swarm_stress_test`
+
+use std::env;
+use std::time::{Duration, Instant};
+
+use chimera_core::client::{connect_with_retry, ReconnectStrategy};
+use tokio::time::interval;
+use tracing::{info, warn, Level};
+use tracing_subscriber::FmtSubscriber;
+
+/// Minimal 1x1 transparent PNG, reused from the client's health check payload
+const TEST_PNG: &[u8] = &[
+    0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A,
+    0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+    0x08, 0x06, 0x00, 0x00, 0x00, 0x1F, 0x15, 0xC4,
+    0x89, 0x00, 0x00, 0x00, 0x0A, 0x49, 0x44, 0x41,
+    0x54, 0x08, 0xD7, 0x63, 0x60, 0x00, 0x00, 0x00,
+    0x02, 0x00, 0x01, 0xE2, 0x21, 0xBC, 0x33, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44,
+    0xAE, 0x42, 0x60, 0x82,
+];
+
+/// Results gathered by a single simulated phantom worker
+#[derive(Default)]
+struct WorkerStats {
+    successes: u64,
+    failures: u64,
+    reconnects: u64,
+    latencies_ms: Vec<u64>,
+}
+
+fn env_usize(key: &str, default: usize) -> usize {
+    env::var(key).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+fn env_f64(key: &str, default: f64) -> f64 {
+    env::var(key).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+fn env_u64(key: &str, default: u64) -> u64 {
+    env::var(key).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+/// Drive one simulated worker through the real `ChimeraClient` path for
+/// the duration of the test, recording latency and outcome of every call
+async fn run_worker(id: usize, request_interval: Duration, deadline: Instant) -> WorkerStats {
+    let mut stats = WorkerStats::default();
+
+    let mut client = match connect_with_retry(&ReconnectStrategy::default()).await {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("worker {} failed initial connect: {}", id, e);
+            stats.failures += 1;
+            return stats;
+        }
+    };
+
+    let mut ticker = interval(request_interval.max(Duration::from_millis(1)));
+
+    while Instant::now() < deadline {
+        ticker.tick().await;
+
+        let start = Instant::now();
+        match client.process_vision(TEST_PNG.to_vec(), Some("stress_test".to_string())).await {
+            Ok(_) => {
+                stats.successes += 1;
+                stats.latencies_ms.push(start.elapsed().as_millis() as u64);
+            }
+            Err(e) => {
+                stats.failures += 1;
+                warn!("worker {} request failed: {}", id, e);
+
+                // Mirror production's heartbeat-failure recovery path
+                if client.reconnect().await.is_ok() {
+                    stats.reconnects += 1;
+                }
+            }
+        }
+    }
+
+    stats
+}
+
+/// Percentile over a sorted slice of latencies (ms): the element at index
+/// `(len - 1) * p`, rounded to the nearest index
+fn percentile(sorted_ms: &[u64], p: f64) -> u64 {
+    if sorted_ms.is_empty() {
+        return 0;
+    }
+    let idx = ((sorted_ms.len() as f64 - 1.0) * p).round() as usize;
+    sorted_ms[idx]
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let subscriber = FmtSubscriber::builder()
+        .with_max_level(Level::INFO)
+        .with_target(false)
+        .with_thread_ids(false)
+        .finish();
+    tracing::subscriber::set_global_default(subscriber)?;
+
+    let worker_count = env_usize("CHIMERA_STRESS_WORKERS", 10);
+    let target_rps = env_f64("CHIMERA_STRESS_RPS", 20.0);
+    let duration = Duration::from_secs(env_u64("CHIMERA_STRESS_DURATION_SECS", 30));
+
+    info!("🔥 Swarm stress test starting");
+    info!("   Workers: {}", worker_count);
+    info!("   Target aggregate rate: {:.1} req/s", target_rps);
+    info!("   Duration: {:?}", duration);
+
+    // Spread the aggregate target evenly across workers
+    let per_worker_interval = Duration::from_secs_f64(worker_count as f64 / target_rps.max(0.001));
+    let deadline = Instant::now() + duration;
+
+    let mut handles = Vec::with_capacity(worker_count);
+    for id in 0..worker_count {
+        handles.push(tokio::spawn(run_worker(id, per_worker_interval, deadline)));
+    }
+
+    let mut results = Vec::with_capacity(worker_count);
+    for handle in handles {
+        results.push(handle.await.expect("worker task panicked"));
+    }
+
+    // Aggregate
+    let total_successes: u64 = results.iter().map(|r| r.successes).sum();
+    let total_failures: u64 = results.iter().map(|r| r.failures).sum();
+    let total_reconnects: u64 = results.iter().map(|r| r.reconnects).sum();
+
+    let mut all_latencies: Vec<u64> = results.iter().flat_map(|r| r.latencies_ms.iter().copied()).collect();
+    all_latencies.sort_unstable();
+
+    let p50 = percentile(&all_latencies, 0.50);
+    let p95 = percentile(&all_latencies, 0.95);
+    let p99 = percentile(&all_latencies, 0.99);
+
+    println!();
+    println!("=== Swarm Stress Test Summary ===");
+    println!("Workers:              {}", worker_count);
+    println!("Duration:             {:?}", duration);
+    println!("Successful requests:  {}", total_successes);
+    println!("Failed requests:      {}", total_failures);
+    println!("Reconnection events:  {}", total_reconnects);
+    println!("Vision latency p50:   {}ms", p50);
+    println!("Vision latency p95:   {}ms", p95);
+    println!("Vision latency p99:   {}ms", p99);
+    println!("==================================");
+
+    Ok(())
+}
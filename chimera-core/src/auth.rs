@@ -0,0 +1,202 @@
+//! HMAC time-limited credentials for authenticating `ChimeraClient` to The Brain
+//!
+//! Mirrors the long-term-credential scheme used for TURN (RFC 5766 §10.2):
+//! the username embeds its own expiry, and the password is an HMAC over that
+//! username, so the Brain can validate a credential against the shared secret
+//! without a round trip to a token service. Credentials are minted lazily and
+//! cached until they're within `REFRESH_SKEW` of expiring.
+
+use std::env;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use tonic::metadata::MetadataValue;
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+use crate::client::env_u64;
+
+type HmacSha1 = Hmac<Sha1>;
+
+/// Env var holding the shared HMAC secret; unset disables auth unless required
+const SECRET_ENV: &str = "CHIMERA_BRAIN_AUTH_SECRET";
+
+/// How long before expiry a cached credential is refreshed
+const REFRESH_SKEW: Duration = Duration::from_secs(30);
+
+/// A `<unix_expiry>:<worker_id>` username plus its HMAC password
+#[derive(Debug, Clone)]
+struct Credential {
+    username: String,
+    password: String,
+    expires_at: SystemTime,
+}
+
+impl Credential {
+    fn mint(secret: &[u8], worker_id: &str, ttl: Duration, now: SystemTime) -> Result<Self, Status> {
+        let expires_at = now + ttl;
+        let expiry_unix = expires_at
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| Status::internal("system clock is before the unix epoch"))?
+            .as_secs();
+        let username = format!("{}:{}", expiry_unix, worker_id);
+
+        let mut mac = HmacSha1::new_from_slice(secret)
+            .map_err(|_| Status::internal("CHIMERA_BRAIN_AUTH_SECRET is not a valid HMAC key"))?;
+        mac.update(username.as_bytes());
+        let password = BASE64.encode(mac.finalize().into_bytes());
+
+        Ok(Self { username, password, expires_at })
+    }
+
+    /// Whether this credential is still valid at least `REFRESH_SKEW` into the future
+    fn is_fresh(&self, now: SystemTime) -> bool {
+        match self.expires_at.duration_since(now) {
+            Ok(remaining) => remaining > REFRESH_SKEW,
+            Err(_) => false,
+        }
+    }
+}
+
+/// Mints and caches short-lived HMAC credentials for one worker
+struct CredentialMinter {
+    secret: Vec<u8>,
+    worker_id: String,
+    ttl: Duration,
+    cached: Mutex<Option<Credential>>,
+}
+
+impl CredentialMinter {
+    fn new(secret: Vec<u8>, worker_id: String, ttl: Duration) -> Self {
+        Self { secret, worker_id, ttl, cached: Mutex::new(None) }
+    }
+
+    /// The current credential, minting a fresh one if the cached one is stale
+    /// or close enough to expiring to need a refresh
+    fn credential(&self) -> Result<Credential, Status> {
+        let now = SystemTime::now();
+        let mut cached = self.cached.lock().expect("credential cache mutex poisoned");
+
+        if let Some(existing) = cached.as_ref() {
+            if existing.is_fresh(now) {
+                return Ok(existing.clone());
+            }
+        }
+
+        let fresh = Credential::mint(&self.secret, &self.worker_id, self.ttl, now)?;
+        *cached = Some(fresh.clone());
+        Ok(fresh)
+    }
+}
+
+/// Tonic interceptor that attaches an `authorization`/`x-chimera-user` HMAC
+/// credential to every outbound request. A no-op when auth is disabled, so
+/// `ChimeraClient` can install it unconditionally via `connect_to`.
+#[derive(Clone)]
+pub struct AuthInterceptor {
+    minter: Option<Arc<CredentialMinter>>,
+}
+
+impl AuthInterceptor {
+    /// Build from environment variables:
+    /// * `CHIMERA_BRAIN_AUTH_SECRET` - shared HMAC secret; unset disables auth
+    /// * `CHIMERA_BRAIN_AUTH_REQUIRED` - `true` to fail fast if the secret is unset (default: false)
+    /// * `CHIMERA_BRAIN_AUTH_TTL_SECS` - credential lifetime in seconds (default: 300)
+    pub fn from_env(worker_id: &str) -> Result<Self, String> {
+        let secret = env::var(SECRET_ENV).ok();
+        let required = env::var("CHIMERA_BRAIN_AUTH_REQUIRED").as_deref() == Ok("true");
+
+        let secret = match secret {
+            Some(secret) => secret,
+            None if required => {
+                return Err(format!(
+                    "CHIMERA_BRAIN_AUTH_REQUIRED=true but {} is unset",
+                    SECRET_ENV
+                ))
+            }
+            None => return Ok(Self { minter: None }),
+        };
+
+        let ttl = Duration::from_secs(env_u64("CHIMERA_BRAIN_AUTH_TTL_SECS", 300));
+        Ok(Self {
+            minter: Some(Arc::new(CredentialMinter::new(secret.into_bytes(), worker_id.to_string(), ttl))),
+        })
+    }
+
+    /// Auth disabled: every call passes through unmodified
+    pub fn disabled() -> Self {
+        Self { minter: None }
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let Some(minter) = &self.minter else {
+            return Ok(request);
+        };
+
+        let credential = minter.credential()?;
+        let authorization: MetadataValue<_> = credential
+            .password
+            .parse()
+            .map_err(|_| Status::internal("failed to encode authorization metadata"))?;
+        let username: MetadataValue<_> = credential
+            .username
+            .parse()
+            .map_err(|_| Status::internal("failed to encode x-chimera-user metadata"))?;
+
+        request.metadata_mut().insert("authorization", authorization);
+        request.metadata_mut().insert("x-chimera-user", username);
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_env_disabled_when_secret_unset() {
+        unsafe {
+            env::remove_var(SECRET_ENV);
+            env::remove_var("CHIMERA_BRAIN_AUTH_REQUIRED");
+        }
+        let interceptor = AuthInterceptor::from_env("worker-1").unwrap();
+        assert!(interceptor.minter.is_none());
+    }
+
+    #[test]
+    fn from_env_fails_fast_when_required_but_unset() {
+        unsafe {
+            env::remove_var(SECRET_ENV);
+            env::set_var("CHIMERA_BRAIN_AUTH_REQUIRED", "true");
+        }
+        let result = AuthInterceptor::from_env("worker-1");
+        unsafe {
+            env::remove_var("CHIMERA_BRAIN_AUTH_REQUIRED");
+        }
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn credential_username_embeds_expiry_and_worker_id() {
+        let minter = CredentialMinter::new(b"shared-secret".to_vec(), "worker-7".to_string(), Duration::from_secs(300));
+        let credential = minter.credential().unwrap();
+        let (expiry, worker_id) = credential.username.split_once(':').unwrap();
+        assert!(expiry.parse::<u64>().is_ok());
+        assert_eq!(worker_id, "worker-7");
+    }
+
+    #[test]
+    fn credential_is_cached_until_refresh_skew() {
+        let minter = CredentialMinter::new(b"shared-secret".to_vec(), "worker-7".to_string(), Duration::from_secs(300));
+        let first = minter.credential().unwrap();
+        let second = minter.credential().unwrap();
+        assert_eq!(first.username, second.username);
+        assert_eq!(first.password, second.password);
+    }
+}
@@ -0,0 +1,283 @@
+//! Local experience-replay cache in front of `ChimeraClient::query_memory`
+//!
+//! The Brain already flags high-confidence (`similarity > 0.95`) memory
+//! results as "Experience Replay ready," but every lookup still pays a
+//! network round trip even when the UI state has been seen before.
+//! `CachedMemoryClient` wraps a `ChimeraClient` (mirroring how `BrainPool` and
+//! `ResilientSession` wrap it) and serves those high-confidence responses
+//! straight out of a local cache, keyed by `screenshot_hash` plus a
+//! normalized query/AX-tree summary.
+//!
+//! The backend is pluggable behind `MemoryCacheBackend`: an in-memory LRU is
+//! always available, and `sled`/`rocksdb`-backed persistent stores are
+//! available behind their matching Cargo feature for workers that want the
+//! cache to survive a restart.
+
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use lru::LruCache;
+use tokio::sync::Mutex;
+use tonic::{async_trait, Status};
+use tracing::debug;
+
+use crate::client::chimera::MemoryResponse;
+use crate::client::{env_u64, ChimeraClient};
+
+/// Cache key for a memory lookup: the exact `screenshot_hash` (when present)
+/// plus a normalized query/AX-tree summary. The query is trimmed and
+/// lowercased and the AX-tree summary is trimmed, so repeat lookups that
+/// differ only in surrounding whitespace or query casing hit the cache;
+/// whitespace inside either string is compared as is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    screenshot_hash: String,
+    normalized_query: String,
+}
+
+impl CacheKey {
+    pub fn new(query: &str, ax_tree_summary: Option<&str>, screenshot_hash: Option<&str>) -> Self {
+        let mut normalized_query = query.trim().to_lowercase();
+        if let Some(ax_tree_summary) = ax_tree_summary {
+            normalized_query.push('\u{0}');
+            normalized_query.push_str(ax_tree_summary.trim());
+        }
+
+        Self {
+            screenshot_hash: screenshot_hash.unwrap_or_default().to_string(),
+            normalized_query,
+        }
+    }
+}
+
+/// A pluggable store for cached `MemoryResponse`s, with TTL-based eviction.
+/// The in-memory LRU below is the default; `sled`/`rocksdb` backends behind
+/// their feature flags implement the same trait.
+#[async_trait]
+pub trait MemoryCacheBackend: Send + Sync {
+    /// Look up `key`, returning `None` on a miss or an expired entry
+    async fn get(&self, key: &CacheKey) -> Option<MemoryResponse>;
+    /// Store `response` under `key`, expiring it after `ttl`
+    async fn put(&self, key: CacheKey, response: MemoryResponse, ttl: Duration);
+    /// Evict `key`, forcing the next lookup to miss regardless of TTL
+    async fn invalidate(&self, key: &CacheKey);
+}
+
+struct CachedEntry {
+    response: MemoryResponse,
+    expires_at: Instant,
+}
+
+/// Always-available in-process LRU backend; entries beyond `capacity` are
+/// evicted least-recently-used, and a `get` past `expires_at` counts as a miss.
+pub struct InMemoryLruCache {
+    entries: Mutex<LruCache<CacheKey, CachedEntry>>,
+}
+
+impl InMemoryLruCache {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::new(1).unwrap());
+        Self { entries: Mutex::new(LruCache::new(capacity)) }
+    }
+}
+
+#[async_trait]
+impl MemoryCacheBackend for InMemoryLruCache {
+    async fn get(&self, key: &CacheKey) -> Option<MemoryResponse> {
+        let mut entries = self.entries.lock().await;
+        match entries.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(entry.response.clone()),
+            Some(_) => {
+                entries.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    async fn put(&self, key: CacheKey, response: MemoryResponse, ttl: Duration) {
+        let mut entries = self.entries.lock().await;
+        entries.put(key, CachedEntry { response, expires_at: Instant::now() + ttl });
+    }
+
+    async fn invalidate(&self, key: &CacheKey) {
+        self.entries.lock().await.pop(key);
+    }
+}
+
+/// Persistent backend storing `(expiry, encoded MemoryResponse)` in a `sled`
+/// tree, so the experience-replay cache survives a worker restart
+#[cfg(feature = "sled-cache")]
+pub struct SledCache {
+    tree: sled::Tree,
+}
+
+#[cfg(feature = "sled-cache")]
+impl SledCache {
+    pub fn open(path: &std::path::Path) -> sled::Result<Self> {
+        Ok(Self { tree: sled::open(path)?.open_tree("experience_replay")? })
+    }
+
+    fn key_bytes(key: &CacheKey) -> Vec<u8> {
+        format!("{}\u{0}{}", key.screenshot_hash, key.normalized_query).into_bytes()
+    }
+}
+
+#[cfg(feature = "sled-cache")]
+#[async_trait]
+impl MemoryCacheBackend for SledCache {
+    async fn get(&self, key: &CacheKey) -> Option<MemoryResponse> {
+        use prost::Message;
+
+        let raw = self.tree.get(Self::key_bytes(key)).ok().flatten()?;
+        let (expires_at_ms, payload) = raw.split_at(8);
+        let expires_at_ms = u64::from_le_bytes(expires_at_ms.try_into().ok()?);
+        if expires_at_ms <= unix_now_ms() {
+            let _ = self.tree.remove(Self::key_bytes(key));
+            return None;
+        }
+        MemoryResponse::decode(payload).ok()
+    }
+
+    async fn put(&self, key: CacheKey, response: MemoryResponse, ttl: Duration) {
+        use prost::Message;
+
+        let expires_at_ms = unix_now_ms() + ttl.as_millis() as u64;
+        let mut value = expires_at_ms.to_le_bytes().to_vec();
+        value.extend_from_slice(&response.encode_to_vec());
+        let _ = self.tree.insert(Self::key_bytes(&key), value);
+    }
+
+    async fn invalidate(&self, key: &CacheKey) {
+        let _ = self.tree.remove(Self::key_bytes(key));
+    }
+}
+
+/// Persistent backend storing `(expiry, encoded MemoryResponse)` in a
+/// `rocksdb` column family, for deployments that already run RocksDB elsewhere
+#[cfg(feature = "rocksdb-cache")]
+pub struct RocksDbCache {
+    db: rocksdb::DB,
+}
+
+#[cfg(feature = "rocksdb-cache")]
+impl RocksDbCache {
+    pub fn open(path: &std::path::Path) -> Result<Self, rocksdb::Error> {
+        Ok(Self { db: rocksdb::DB::open_default(path)? })
+    }
+
+    fn key_bytes(key: &CacheKey) -> Vec<u8> {
+        format!("{}\u{0}{}", key.screenshot_hash, key.normalized_query).into_bytes()
+    }
+}
+
+#[cfg(feature = "rocksdb-cache")]
+#[async_trait]
+impl MemoryCacheBackend for RocksDbCache {
+    async fn get(&self, key: &CacheKey) -> Option<MemoryResponse> {
+        use prost::Message;
+
+        let raw = self.db.get(Self::key_bytes(key)).ok().flatten()?;
+        let (expires_at_ms, payload) = raw.split_at(8);
+        let expires_at_ms = u64::from_le_bytes(expires_at_ms.try_into().ok()?);
+        if expires_at_ms <= unix_now_ms() {
+            let _ = self.db.delete(Self::key_bytes(key));
+            return None;
+        }
+        MemoryResponse::decode(payload).ok()
+    }
+
+    async fn put(&self, key: CacheKey, response: MemoryResponse, ttl: Duration) {
+        use prost::Message;
+
+        let expires_at_ms = unix_now_ms() + ttl.as_millis() as u64;
+        let mut value = expires_at_ms.to_le_bytes().to_vec();
+        value.extend_from_slice(&response.encode_to_vec());
+        let _ = self.db.put(Self::key_bytes(&key), value);
+    }
+
+    async fn invalidate(&self, key: &CacheKey) {
+        let _ = self.db.delete(Self::key_bytes(key));
+    }
+}
+
+#[cfg(any(feature = "sled-cache", feature = "rocksdb-cache"))]
+fn unix_now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Wraps a `ChimeraClient`, serving `query_memory` calls out of a
+/// `MemoryCacheBackend` when a prior high-confidence result is cached
+pub struct CachedMemoryClient {
+    client: ChimeraClient,
+    backend: Arc<dyn MemoryCacheBackend>,
+    ttl: Duration,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CachedMemoryClient {
+    pub fn new(client: ChimeraClient, backend: Arc<dyn MemoryCacheBackend>, ttl: Duration) -> Self {
+        Self { client, backend, ttl, hits: AtomicU64::new(0), misses: AtomicU64::new(0) }
+    }
+
+    /// Wrap `client` with the default in-memory LRU backend, sized and
+    /// TTL'd from environment variables:
+    /// * `CHIMERA_CACHE_CAPACITY` - max cached entries (default: 512)
+    /// * `CHIMERA_CACHE_TTL_SECS` - entry lifetime in seconds (default: 600)
+    pub fn from_env(client: ChimeraClient) -> Self {
+        let capacity = env_u64("CHIMERA_CACHE_CAPACITY", 512) as usize;
+        let ttl = Duration::from_secs(env_u64("CHIMERA_CACHE_TTL_SECS", 600));
+        Self::new(client, Arc::new(InMemoryLruCache::new(capacity)), ttl)
+    }
+
+    /// Query the Hive Mind, serving a cached high-confidence result if one
+    /// exists. `bypass_cache` skips the lookup (but still refreshes the
+    /// cache with the fresh result) for callers that need an up-to-date answer.
+    pub async fn query_memory(
+        &mut self,
+        query: String,
+        ax_tree_summary: Option<String>,
+        screenshot_hash: Option<String>,
+        bypass_cache: bool,
+    ) -> Result<MemoryResponse, Status> {
+        let key = CacheKey::new(&query, ax_tree_summary.as_deref(), screenshot_hash.as_deref());
+
+        if !bypass_cache {
+            if let Some(cached) = self.backend.get(&key).await {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                debug!("🗂️ Experience-replay cache hit, skipping the Brain round trip");
+                return Ok(cached);
+            }
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let response = self.client.query_memory(query, ax_tree_summary, screenshot_hash).await?;
+
+        if response.results.iter().any(|r| r.similarity > 0.95) {
+            self.backend.put(key, response.clone(), self.ttl).await;
+        }
+
+        Ok(response)
+    }
+
+    /// Force-evict any cached entry for this exact lookup
+    pub async fn invalidate(&self, query: &str, ax_tree_summary: Option<&str>, screenshot_hash: Option<&str>) {
+        self.backend.invalidate(&CacheKey::new(query, ax_tree_summary, screenshot_hash)).await;
+    }
+
+    /// Cache hits so far, for logging alongside heartbeat stats
+    pub fn hit_count(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Cache misses so far (including bypassed lookups)
+    pub fn miss_count(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
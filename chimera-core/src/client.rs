@@ -3,11 +3,23 @@
 //! This module handles communication with the Python Brain service
 //! for vision processing and memory queries with retry logic and resilience.
 
+use std::collections::HashMap;
 use std::env;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
 use tonic::{Request, Status, Code};
+use tonic::service::interceptor::InterceptedService;
 use tracing::{info, error, warn, debug};
 use tokio::time::sleep;
+use tokio::sync::{mpsc, Mutex, OnceCell, Semaphore, OwnedSemaphorePermit};
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::Stream;
+use rand::Rng;
+
+use crate::auth::AuthInterceptor;
 
 // Include generated proto code
 pub mod chimera {
@@ -17,6 +29,10 @@ pub mod chimera {
 use chimera::brain_client::BrainClient;
 use chimera::{ProcessVisionRequest, VisionResponse, QueryMemoryRequest, MemoryResponse};
 
+/// Channel type used by `ChimeraClient`: the raw transport channel wrapped in
+/// the HMAC auth interceptor (a no-op when auth is disabled)
+type AuthedChannel = InterceptedService<tonic::transport::Channel, AuthInterceptor>;
+
 /// Get the Brain service address from environment or use default
 pub fn get_brain_address() -> String {
     // Check for Railway environment (production)
@@ -27,28 +43,621 @@ pub fn get_brain_address() -> String {
                 .unwrap_or_else(|_| "http://chimera-brain.railway.internal:50051".to_string());
         }
     }
-    
+
     // Local development or explicit override
     env::var("CHIMERA_BRAIN_ADDRESS")
         .unwrap_or_else(|_| "http://localhost:50051".to_string())
 }
 
-/// Connect to The Brain with exponential backoff retry
-/// 
+/// Get every Brain replica address for the connection pool (see `pool::BrainPool`)
+///
+/// Reads a comma-separated `CHIMERA_BRAIN_ADDRESSES`, then falls back to
+/// splitting `CHIMERA_BRAIN_ADDRESS` on commas (so a single-replica deployment
+/// needs no config change), then to `get_brain_address`'s single default.
+pub fn get_brain_addresses() -> Vec<String> {
+    let raw = env::var("CHIMERA_BRAIN_ADDRESSES")
+        .or_else(|_| env::var("CHIMERA_BRAIN_ADDRESS"))
+        .unwrap_or_else(|_| get_brain_address());
+
+    raw.split(',')
+        .map(str::trim)
+        .filter(|addr| !addr.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Parse a `u64` environment variable, falling back to `default` if unset or invalid
+pub(crate) fn env_u64(key: &str, default: u64) -> u64 {
+    env::var(key).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+/// Parse a `u32` environment variable, falling back to `default` if unset or invalid
+pub(crate) fn env_u32(key: &str, default: u32) -> u32 {
+    env::var(key).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+/// Parse an `f64` environment variable, falling back to `default` if unset or invalid
+fn env_f64(key: &str, default: f64) -> f64 {
+    env::var(key).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+/// Whether `status` reflects a transient, connection-level failure (the Brain
+/// is unreachable or overloaded) as opposed to an application-level rejection
+/// like a malformed request
+pub(crate) fn is_connection_error(status: &Status) -> bool {
+    matches!(status.code(), Code::Unavailable | Code::Internal | Code::DeadlineExceeded)
+}
+
+/// Resolve this process's worker identity, matching the scheme `main` uses
+/// for logging (Railway replica id, then `WORKER_ID`, then a local default)
+fn resolve_worker_id() -> String {
+    env::var("RAILWAY_REPLICA_ID")
+        .or_else(|_| env::var("WORKER_ID"))
+        .unwrap_or_else(|_| "local-0".to_string())
+}
+
+/// Process-wide token bucket rate limiter, shared by every `ChimeraClient`
+/// in this process and keyed per worker id
+static RATE_LIMITER: OnceCell<RateLimiter> = OnceCell::const_new();
+
+async fn rate_limiter() -> &'static RateLimiter {
+    RATE_LIMITER.get_or_init(|| async { RateLimiter::from_env() }).await
+}
+
+/// Per-worker token bucket, refilled lazily from elapsed time on access
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+    last_used: Instant,
+}
+
+impl TokenBucket {
+    fn new(burst: f64) -> Self {
+        let now = Instant::now();
+        Self { tokens: burst, last_refill: now, last_used: now }
+    }
+
+    fn refill(&mut self, rate: f64, burst: f64) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rate).min(burst);
+        self.last_refill = now;
+    }
+}
+
+/// Concurrent token-bucket rate limiter for outbound Brain traffic, keyed
+/// per-worker so many phantom workers sharing one Brain don't collectively
+/// trip server-side abuse detection
+///
+/// Buckets refill lazily (no per-key background ticking); a single
+/// background task periodically sweeps buckets idle beyond a TTL so memory
+/// stays bounded across many short-lived workers. Calls that exceed the
+/// budget wait for the next available token rather than erroring.
+struct RateLimiter {
+    buckets: Arc<Mutex<HashMap<String, TokenBucket>>>,
+    burst: f64,
+    rate: f64,
+    gc_handle: tokio::task::JoinHandle<()>,
+}
+
+impl RateLimiter {
+    /// Build from environment variables:
+    /// * `CHIMERA_RATE_BURST` - bucket capacity (default: 10)
+    /// * `CHIMERA_RATE_PER_SEC` - refill rate, tokens/sec (default: 5)
+    /// * `CHIMERA_RATE_BUCKET_TTL_SECS` - GC idle TTL (default: 300)
+    fn from_env() -> Self {
+        let burst = env_f64("CHIMERA_RATE_BURST", 10.0);
+        let rate = env_f64("CHIMERA_RATE_PER_SEC", 5.0);
+        let idle_ttl = Duration::from_secs(env_u64("CHIMERA_RATE_BUCKET_TTL_SECS", 300));
+        Self::new(burst, rate, idle_ttl)
+    }
+
+    fn new(burst: f64, rate: f64, idle_ttl: Duration) -> Self {
+        let buckets: Arc<Mutex<HashMap<String, TokenBucket>>> = Arc::new(Mutex::new(HashMap::new()));
+        let gc_buckets = Arc::clone(&buckets);
+        let gc_interval = (idle_ttl / 2).max(Duration::from_secs(1));
+
+        let gc_handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(gc_interval);
+            loop {
+                ticker.tick().await;
+                let mut map = gc_buckets.lock().await;
+                let before = map.len();
+                map.retain(|_, bucket| bucket.last_used.elapsed() < idle_ttl);
+                let removed = before - map.len();
+                if removed > 0 {
+                    debug!("🧹 Rate limiter GC dropped {} idle bucket(s)", removed);
+                }
+            }
+        });
+
+        Self { buckets, burst, rate, gc_handle }
+    }
+
+    /// Wait until a token is available for `worker_id`, then consume it
+    async fn acquire(&self, worker_id: &str) {
+        loop {
+            let wait = {
+                let mut map = self.buckets.lock().await;
+                let bucket = map.entry(worker_id.to_string())
+                    .or_insert_with(|| TokenBucket::new(self.burst));
+                bucket.refill(self.rate, self.burst);
+                bucket.last_used = Instant::now();
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    None
+                } else {
+                    let deficit = 1.0 - bucket.tokens;
+                    Some(Duration::from_secs_f64(deficit / self.rate))
+                }
+            };
+
+            match wait {
+                None => return,
+                Some(wait) => sleep(wait).await,
+            }
+        }
+    }
+}
+
+impl Drop for RateLimiter {
+    fn drop(&mut self) {
+        self.gc_handle.abort();
+    }
+}
+
+/// Reconnection strategy used by `connect_with_retry` and the heartbeat's
+/// failure-recovery path to space out connection attempts against The Brain.
+#[derive(Debug, Clone)]
+pub enum ReconnectStrategy {
+    /// Retry a fixed number of times with a constant delay between attempts.
+    Fixed { attempts: u32, delay: Duration },
+    /// Retry with exponential backoff and full jitter: each delay is drawn
+    /// uniformly from `0..=min(cap, base * 2^attempt)` so concurrent workers
+    /// don't synchronize their reconnect storms.
+    ExponentialBackoff {
+        base: Duration,
+        cap: Duration,
+        max_attempts: u32,
+    },
+}
+
+impl ReconnectStrategy {
+    /// Build a strategy from environment variables:
+    /// * `CHIMERA_RECONNECT_STRATEGY` - `fixed` (default) or `exponential`
+    /// * `CHIMERA_RECONNECT_ATTEMPTS` - max attempts (default: 5)
+    /// * `CHIMERA_RECONNECT_DELAY_MS` - fixed delay in ms (default: 100)
+    /// * `CHIMERA_RECONNECT_BASE_MS` - exponential base delay in ms (default: 100)
+    /// * `CHIMERA_RECONNECT_CAP_MS` - exponential cap in ms (default: 10000)
+    pub fn from_env() -> Self {
+        match env::var("CHIMERA_RECONNECT_STRATEGY").as_deref() {
+            Ok("exponential") => ReconnectStrategy::ExponentialBackoff {
+                base: Duration::from_millis(env_u64("CHIMERA_RECONNECT_BASE_MS", 100)),
+                cap: Duration::from_millis(env_u64("CHIMERA_RECONNECT_CAP_MS", 10_000)),
+                max_attempts: env_u32("CHIMERA_RECONNECT_ATTEMPTS", 5),
+            },
+            _ => ReconnectStrategy::Fixed {
+                attempts: env_u32("CHIMERA_RECONNECT_ATTEMPTS", 5),
+                delay: Duration::from_millis(env_u64("CHIMERA_RECONNECT_DELAY_MS", 100)),
+            },
+        }
+    }
+
+    /// Maximum number of connection attempts before giving up
+    pub(crate) fn max_attempts(&self) -> u32 {
+        match self {
+            ReconnectStrategy::Fixed { attempts, .. } => *attempts,
+            ReconnectStrategy::ExponentialBackoff { max_attempts, .. } => *max_attempts,
+        }
+    }
+
+    /// Delay to wait before the next attempt, given how many attempts have
+    /// already been made (1-indexed)
+    pub(crate) fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        match self {
+            ReconnectStrategy::Fixed { delay, .. } => *delay,
+            ReconnectStrategy::ExponentialBackoff { base, cap, .. } => {
+                let scaled = base.as_millis().saturating_mul(1u128 << attempt.min(32));
+                let capped = scaled.min(cap.as_millis()).max(1);
+                let jittered_ms = rand::thread_rng().gen_range(0..=capped) as u64;
+                Duration::from_millis(jittered_ms)
+            }
+        }
+    }
+}
+
+impl Default for ReconnectStrategy {
+    fn default() -> Self {
+        ReconnectStrategy::Fixed {
+            attempts: 5,
+            delay: Duration::from_millis(100),
+        }
+    }
+}
+
+/// Tunable configuration for a `ChimeraClient`: reconnection behavior plus
+/// the heartbeat cadence, so both can be adjusted via env without a redeploy.
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    pub reconnect_strategy: ReconnectStrategy,
+    pub heartbeat_interval: Duration,
+}
+
+impl ClientConfig {
+    /// Build a config from environment variables (see `ReconnectStrategy::from_env`
+    /// plus `CHIMERA_HEARTBEAT_INTERVAL_SECS`, default: 60)
+    pub fn from_env() -> Self {
+        Self {
+            reconnect_strategy: ReconnectStrategy::from_env(),
+            heartbeat_interval: Duration::from_secs(env_u64("CHIMERA_HEARTBEAT_INTERVAL_SECS", 60)),
+        }
+    }
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            reconnect_strategy: ReconnectStrategy::default(),
+            heartbeat_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+/// AIMD congestion controller bounding in-flight `process_vision` requests
+///
+/// Mirrors TCP's additive-increase/multiplicative-decrease flow control: the
+/// window (`cwnd`) grows by one permit per full window of successes and
+/// collapses by 30% on a timeout/transport error or a latency spike, so a
+/// slow or overloaded Brain sheds load instead of piling up requests.
+pub struct AimdController {
+    semaphore: Arc<Semaphore>,
+    state: Mutex<AimdState>,
+}
+
+struct AimdState {
+    cwnd: f64,
+    /// Number of permits the semaphore is intended to hold right now
+    permits_issued: usize,
+    /// Permits still physically outstanding that must be forgotten (not
+    /// released) the next time they're acquired, to realize a pending shrink
+    pending_shrink: usize,
+    /// Smoothed RTT (EWMA, alpha ~= 0.125), in milliseconds
+    srtt_ms: Option<f64>,
+    in_flight: usize,
+}
+
+/// A held slot in the congestion window; report the outcome via
+/// `AimdController::on_success`/`on_failure` to release it and update `cwnd`
+pub struct VisionPermit {
+    permit: OwnedSemaphorePermit,
+    start: Instant,
+}
+
+impl AimdController {
+    /// Smoothing factor for the RTT EWMA
+    const SRTT_ALPHA: f64 = 0.125;
+    /// Congestion window never shrinks below this
+    const MIN_CWND: f64 = 1.0;
+    /// Multiplicative decrease factor applied on congestion
+    const DECREASE_FACTOR: f64 = 0.7;
+
+    /// Create a controller starting with a small window (e.g. 2) so the
+    /// client probes gently before ramping up
+    pub fn new(initial_cwnd: usize) -> Self {
+        let initial_cwnd = initial_cwnd.max(1);
+        Self {
+            semaphore: Arc::new(Semaphore::new(initial_cwnd)),
+            state: Mutex::new(AimdState {
+                cwnd: initial_cwnd as f64,
+                permits_issued: initial_cwnd,
+                pending_shrink: 0,
+                srtt_ms: None,
+                in_flight: 0,
+            }),
+        }
+    }
+
+    /// Wait for a slot in the congestion window
+    pub async fn acquire(&self) -> VisionPermit {
+        loop {
+            let permit = self.semaphore.clone().acquire_owned().await
+                .expect("congestion semaphore is never closed");
+
+            let mut state = self.state.lock().await;
+            if state.pending_shrink > 0 {
+                // This permit belongs to a window size we've already shrunk away from
+                permit.forget();
+                state.pending_shrink -= 1;
+                continue;
+            }
+
+            state.in_flight += 1;
+            return VisionPermit { permit, start: Instant::now() };
+        }
+    }
+
+    /// Report a successful request: grow `cwnd` additively unless the
+    /// latency itself signals congestion (> 2x smoothed RTT)
+    pub async fn on_success(&self, held: VisionPermit) {
+        let latency_ms = held.start.elapsed().as_secs_f64() * 1000.0;
+        drop(held.permit);
+
+        let mut state = self.state.lock().await;
+        state.in_flight = state.in_flight.saturating_sub(1);
+
+        let congested = state.srtt_ms.is_some_and(|srtt| latency_ms > srtt * 2.0);
+        state.srtt_ms = Some(match state.srtt_ms {
+            Some(srtt) => srtt + Self::SRTT_ALPHA * (latency_ms - srtt),
+            None => latency_ms,
+        });
+
+        if congested {
+            warn!("🐢 Vision latency {:.0}ms exceeded 2x smoothed RTT, treating as congestion", latency_ms);
+            self.decrease(&mut state);
+        } else {
+            state.cwnd += 1.0 / state.cwnd;
+            self.grow_to(&mut state);
+        }
+    }
+
+    /// Report a failed request (timeout or transport error): multiplicatively
+    /// shrink `cwnd` and enter a brief recovery hold before the next probe
+    pub async fn on_failure(&self, held: VisionPermit) {
+        drop(held.permit);
+
+        let mut state = self.state.lock().await;
+        state.in_flight = state.in_flight.saturating_sub(1);
+        self.decrease(&mut state);
+        drop(state);
+
+        // Brief recovery hold so we don't immediately re-probe into the same failure
+        sleep(Duration::from_millis(50)).await;
+    }
+
+    fn decrease(&self, state: &mut AimdState) {
+        state.cwnd = (state.cwnd * Self::DECREASE_FACTOR).max(Self::MIN_CWND);
+        let target = state.cwnd.floor().max(1.0) as usize;
+        if target < state.permits_issued {
+            state.pending_shrink += state.permits_issued - target;
+            state.permits_issued = target;
+        }
+    }
+
+    fn grow_to(&self, state: &mut AimdState) {
+        let target = state.cwnd.floor().max(1.0) as usize;
+        if target > state.permits_issued {
+            let delta = target - state.permits_issued;
+            if state.pending_shrink >= delta {
+                state.pending_shrink -= delta;
+            } else {
+                let to_add = delta - state.pending_shrink;
+                state.pending_shrink = 0;
+                self.semaphore.add_permits(to_add);
+            }
+            state.permits_issued = target;
+        }
+    }
+
+    /// Current congestion window size (fractional, pre-floor)
+    pub async fn cwnd(&self) -> f64 {
+        self.state.lock().await.cwnd
+    }
+
+    /// Number of vision requests currently in flight
+    pub async fn in_flight(&self) -> usize {
+        self.state.lock().await.in_flight
+    }
+}
+
+/// Circuit breaker state, exposed so callers can surface it in health dashboards
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerState {
+    /// Calls go through normally
+    Closed,
+    /// Short-circuiting calls with a fast error until the cooldown elapses
+    Open,
+    /// Cooldown elapsed; a single probe call is allowed through to test recovery
+    HalfOpen,
+}
+
+struct BreakerInner {
+    state: BreakerState,
+    consecutive_failures: u32,
+    opened_at: Instant,
+    cooldown: Duration,
+    /// Set while the single half-open probe is outstanding, so concurrent
+    /// callers don't all rush through as "the" probe
+    probe_in_flight: bool,
+}
+
+/// Circuit breaker tracking consecutive failures across `process_vision`/`query_memory`
+/// calls, so an unreachable Brain fails fast instead of absorbing a thundering
+/// herd of doomed per-call retries.
+///
+/// Trips `Open` after `threshold` consecutive failures. Once the cooldown
+/// elapses, moves to `HalfOpen` and lets exactly one probe through: success
+/// closes the breaker, failure re-opens it with the cooldown doubled (capped
+/// at `max_cooldown`).
+pub struct CircuitBreaker {
+    threshold: u32,
+    base_cooldown: Duration,
+    max_cooldown: Duration,
+    inner: Mutex<BreakerInner>,
+}
+
+impl CircuitBreaker {
+    /// Build a breaker from environment variables:
+    /// * `CHIMERA_BREAKER_THRESHOLD` - consecutive failures to trip open (default: 5)
+    /// * `CHIMERA_BREAKER_COOLDOWN_MS` - initial open cooldown in ms (default: 1000)
+    /// * `CHIMERA_BREAKER_MAX_COOLDOWN_MS` - cooldown cap in ms (default: 30000)
+    pub fn from_env() -> Self {
+        Self::new(
+            env_u32("CHIMERA_BREAKER_THRESHOLD", 5),
+            Duration::from_millis(env_u64("CHIMERA_BREAKER_COOLDOWN_MS", 1_000)),
+            Duration::from_millis(env_u64("CHIMERA_BREAKER_MAX_COOLDOWN_MS", 30_000)),
+        )
+    }
+
+    pub fn new(threshold: u32, base_cooldown: Duration, max_cooldown: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            base_cooldown,
+            max_cooldown,
+            inner: Mutex::new(BreakerInner {
+                state: BreakerState::Closed,
+                consecutive_failures: 0,
+                opened_at: Instant::now(),
+                cooldown: base_cooldown,
+                probe_in_flight: false,
+            }),
+        }
+    }
+
+    /// Check whether a call is allowed through; if the breaker is `Open` and the
+    /// cooldown has elapsed, this transitions to `HalfOpen` and admits one probe.
+    async fn admit(&self) -> Result<(), Status> {
+        let mut inner = self.inner.lock().await;
+        match inner.state {
+            BreakerState::Closed => Ok(()),
+            BreakerState::Open => {
+                if inner.opened_at.elapsed() >= inner.cooldown {
+                    inner.state = BreakerState::HalfOpen;
+                    inner.probe_in_flight = true;
+                    debug!("🔌 Circuit breaker half-open, admitting a probe call");
+                    Ok(())
+                } else {
+                    Err(Status::unavailable("circuit breaker open: Brain presumed down"))
+                }
+            }
+            BreakerState::HalfOpen => {
+                if inner.probe_in_flight {
+                    Err(Status::unavailable("circuit breaker half-open: probe already in flight"))
+                } else {
+                    inner.probe_in_flight = true;
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Record a successful call: closes the breaker and resets the cooldown
+    async fn on_success(&self) {
+        let mut inner = self.inner.lock().await;
+        if inner.state != BreakerState::Closed {
+            info!("✅ Circuit breaker closed after successful probe");
+        }
+        inner.state = BreakerState::Closed;
+        inner.consecutive_failures = 0;
+        inner.cooldown = self.base_cooldown;
+        inner.probe_in_flight = false;
+    }
+
+    /// Record a failed call: trips the breaker open once `threshold` consecutive
+    /// failures accumulate, or re-opens with a doubled cooldown if the probe failed
+    async fn on_failure(&self) {
+        let mut inner = self.inner.lock().await;
+        inner.probe_in_flight = false;
+        inner.consecutive_failures += 1;
+
+        match inner.state {
+            BreakerState::Closed => {
+                if inner.consecutive_failures >= self.threshold {
+                    inner.state = BreakerState::Open;
+                    inner.opened_at = Instant::now();
+                    inner.cooldown = self.base_cooldown;
+                    warn!("🚨 Circuit breaker tripped open after {} consecutive failures", inner.consecutive_failures);
+                }
+            }
+            BreakerState::HalfOpen => {
+                inner.state = BreakerState::Open;
+                inner.opened_at = Instant::now();
+                inner.cooldown = (inner.cooldown * 2).min(self.max_cooldown);
+                warn!("🚨 Circuit breaker probe failed, re-opened with cooldown {:?}", inner.cooldown);
+            }
+            BreakerState::Open => {}
+        }
+    }
+
+    /// Reset to `Closed`, e.g. after a fresh `reconnect()`
+    async fn reset(&self) {
+        let mut inner = self.inner.lock().await;
+        inner.state = BreakerState::Closed;
+        inner.consecutive_failures = 0;
+        inner.cooldown = self.base_cooldown;
+        inner.probe_in_flight = false;
+    }
+
+    /// Current breaker state, for health dashboards
+    pub async fn state(&self) -> BreakerState {
+        self.inner.lock().await.state
+    }
+}
+
+/// Default buffer for the outbound half of `process_vision_stream`
+const VISION_STREAM_BUFFER: usize = 16;
+
+/// Outbound half of `process_vision_stream`: push frames through `send` as
+/// they're captured. Dropping this (or having it closed from the inbound side
+/// after a mid-stream error) ends the gRPC request stream cleanly.
+pub type VisionFrameSender = mpsc::Sender<ProcessVisionRequest>;
+
+/// Wraps the outbound frame stream so the inbound half can force it closed
+/// (via `closed`) the moment a mid-stream error is observed, instead of
+/// continuing to feed a request stream the Brain has already errored out on.
+struct ClosableStream<S> {
+    inner: S,
+    closed: Arc<AtomicBool>,
+}
+
+impl<S: Stream + Unpin> Stream for ClosableStream<S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.closed.load(AtomicOrdering::Relaxed) {
+            return Poll::Ready(None);
+        }
+        Pin::new(&mut self.inner).poll_next(cx)
+    }
+}
+
+/// Inbound half of `process_vision_stream`: the raw `VisionResponse` stream,
+/// but flips `closed` (ending the paired outbound stream) the first time the
+/// Brain reports an error, rather than leaving the send half open against a
+/// connection that's already given up.
+struct VisionStream {
+    inner: tonic::Streaming<VisionResponse>,
+    closed: Arc<AtomicBool>,
+}
+
+impl Stream for VisionStream {
+    type Item = Result<VisionResponse, Status>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = Pin::new(&mut self.inner).poll_next(cx);
+        if let Poll::Ready(Some(Err(_))) = &poll {
+            self.closed.store(true, AtomicOrdering::Relaxed);
+        }
+        poll
+    }
+}
+
+/// Connect to The Brain, retrying according to `strategy`
+///
 /// # Arguments
-/// * `max_retries` - Maximum number of retry attempts (default: 5)
-/// 
+/// * `strategy` - Controls attempt count and delay between attempts
+///
 /// # Returns
 /// * Result with ChimeraClient or error
-pub async fn connect_with_retry(max_retries: u32) -> Result<ChimeraClient, Box<dyn std::error::Error>> {
+pub async fn connect_with_retry(strategy: &ReconnectStrategy) -> Result<ChimeraClient, Box<dyn std::error::Error>> {
     let address = get_brain_address();
+    let max_retries = strategy.max_attempts();
     let mut attempt = 0;
-    let mut delay_ms = 100; // Start with 100ms delay
-    
+
     loop {
         attempt += 1;
         info!("🔗 Connecting to The Brain (attempt {}/{}): {}", attempt, max_retries, address);
-        
+
         match ChimeraClient::connect_to(&address).await {
             Ok(client) => {
                 info!("✅ Connected to The Brain after {} attempt(s)", attempt);
@@ -59,52 +668,75 @@ pub async fn connect_with_retry(max_retries: u32) -> Result<ChimeraClient, Box<d
                     error!("❌ Failed to connect to The Brain after {} attempts: {}", max_retries, e);
                     return Err(format!("Connection failed after {} attempts: {}", max_retries, e).into());
                 }
-                
-                warn!("⚠️ Connection attempt {} failed: {}, retrying in {}ms...", attempt, e, delay_ms);
-                sleep(Duration::from_millis(delay_ms)).await;
-                
-                // Exponential backoff: double the delay each time (max 10 seconds)
-                delay_ms = (delay_ms * 2).min(10_000);
+
+                let delay = strategy.delay_for_attempt(attempt);
+                warn!("⚠️ Connection attempt {} failed: {}, retrying in {:?}...", attempt, e, delay);
+                sleep(delay).await;
             }
         }
     }
 }
 
+/// Default starting congestion window for vision requests
+const DEFAULT_INITIAL_CWND: usize = 2;
+
 /// Client for communicating with The Brain service
 pub struct ChimeraClient {
-    client: BrainClient<tonic::transport::Channel>,
+    client: BrainClient<AuthedChannel>,
     address: String,
+    congestion: Arc<AimdController>,
+    breaker: Arc<CircuitBreaker>,
+    worker_id: String,
 }
 
 impl ChimeraClient {
     /// Connect to The Brain service (single attempt, no retry)
-    pub async fn connect() -> Result<Self, tonic::transport::Error> {
+    pub async fn connect() -> Result<Self, Box<dyn std::error::Error>> {
         let address = get_brain_address();
         Self::connect_to(&address).await
     }
-    
-    /// Connect to a specific address
-    pub async fn connect_to(addr: &str) -> Result<Self, tonic::transport::Error> {
+
+    /// Build the channel used by `connect_to`, with HTTP/2 keepalive pings and a
+    /// connect timeout so a half-dead TCP connection is detected proactively
+    /// rather than only surfacing via per-RPC deadlines.
+    fn channel_endpoint(addr: &str) -> Result<tonic::transport::Endpoint, tonic::transport::Error> {
+        Ok(tonic::transport::Channel::from_shared(addr.to_string())?
+            .connect_timeout(Duration::from_secs(env_u64("CHIMERA_CONNECT_TIMEOUT_SECS", 10)))
+            .http2_keep_alive_interval(Duration::from_secs(env_u64("CHIMERA_KEEPALIVE_INTERVAL_SECS", 30)))
+            .keep_alive_timeout(Duration::from_secs(env_u64("CHIMERA_KEEPALIVE_TIMEOUT_SECS", 10)))
+            .keep_alive_while_idle(true))
+    }
+
+    /// Connect to a specific address, installing the HMAC auth interceptor
+    /// (see `CHIMERA_BRAIN_AUTH_SECRET` in `auth`)
+    pub async fn connect_to(addr: &str) -> Result<Self, Box<dyn std::error::Error>> {
         info!("🔗 Connecting to The Brain at: {}", addr);
-        
-        let client = BrainClient::connect(addr.to_string()).await?;
-        
+
+        let worker_id = resolve_worker_id();
+        let auth = AuthInterceptor::from_env(&worker_id)?;
+        let channel = Self::channel_endpoint(addr)?.connect().await?;
+        let client = BrainClient::with_interceptor(channel, auth);
+
         info!("✅ Connected to The Brain");
-        
-        Ok(Self { 
+
+        Ok(Self {
             client,
             address: addr.to_string(),
+            congestion: Arc::new(AimdController::new(DEFAULT_INITIAL_CWND)),
+            breaker: Arc::new(CircuitBreaker::from_env()),
+            worker_id,
         })
     }
-    
+
     /// Reconnect to The Brain (useful after connection loss)
     pub async fn reconnect(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         info!("🔄 Reconnecting to The Brain...");
         let address = self.address.clone();
-        
+
         match Self::connect_to(&address).await {
             Ok(new_client) => {
                 self.client = new_client.client;
+                self.breaker.reset().await;
                 info!("✅ Reconnected to The Brain");
                 Ok(())
             }
@@ -135,8 +767,11 @@ impl ChimeraClient {
             text_command: text_command.unwrap_or_default(),
         });
         
+        // Respect the per-worker rate budget before touching the network
+        rate_limiter().await.acquire(&self.worker_id).await;
+
         info!("📸 Sending vision request to The Brain...");
-        
+
         // Retry logic for gRPC calls
         let max_retries = 5;
         let mut attempt = 0;
@@ -144,14 +779,25 @@ impl ChimeraClient {
         
         loop {
             attempt += 1;
-            
+
+            // Fail fast without touching the network if the breaker is tripped
+            if let Err(status) = self.breaker.admit().await {
+                warn!("⚡ Vision request short-circuited by open breaker (attempt {})", attempt);
+                return Err(status);
+            }
+
+            // Bound in-flight requests to The Brain via the AIMD congestion window
+            let permit = self.congestion.acquire().await;
+
             match self.client.process_vision(request.clone()).await {
                 Ok(response) => {
                     let latency = start_time.elapsed();
                     let vision_response = response.into_inner();
-                    
+                    self.congestion.on_success(permit).await;
+                    self.breaker.on_success().await;
+
                     debug!("📸 Vision request completed in {:?} (attempt {})", latency, attempt);
-                    
+
                     if vision_response.found {
                         info!(
                             "✅ Brain found coordinates: ({}, {}) with confidence: {:.2} (latency: {:?})",
@@ -163,16 +809,15 @@ impl ChimeraClient {
                     } else {
                         warn!("⚠️ Brain processed vision but found no specific coordinates (latency: {:?})", latency);
                     }
-                    
+
                     return Ok(vision_response);
                 }
                 Err(status) => {
                     // Check if error is retryable
-                    let is_retryable = matches!(
-                        status.code(),
-                        Code::Unavailable | Code::Internal | Code::DeadlineExceeded
-                    );
-                    
+                    let is_retryable = is_connection_error(&status);
+                    self.congestion.on_failure(permit).await;
+                    self.breaker.on_failure().await;
+
                     if !is_retryable || attempt >= max_retries {
                         let latency = start_time.elapsed();
                         error!(
@@ -181,12 +826,12 @@ impl ChimeraClient {
                         );
                         return Err(status);
                     }
-                    
+
                     warn!(
                         "⚠️ Vision request failed (attempt {}/{}): Status={:?}, retrying in {}ms...",
                         attempt, max_retries, status.code(), delay_ms
                     );
-                    
+
                     sleep(Duration::from_millis(delay_ms)).await;
                     delay_ms = (delay_ms * 2).min(10_000); // Exponential backoff, max 10s
                 }
@@ -194,6 +839,41 @@ impl ChimeraClient {
         }
     }
     
+    /// Open a bidirectional streaming counterpart to `process_vision`, for
+    /// continuous UI tracking where re-dialing a unary call per frame would
+    /// waste latency: push frames (screenshot + optional text command)
+    /// through the returned sender, and read a `VisionResponse` per frame off
+    /// the returned stream as the Brain emits them.
+    ///
+    /// Requires the bidi-streaming `ProcessVisionStream` rpc
+    /// (`stream ProcessVisionRequest` -> `stream VisionResponse`) alongside
+    /// the existing unary `ProcessVision` in `chimera.proto`; that proto is
+    /// maintained outside this repo (see `build.rs`), so the generated
+    /// `BrainClient::process_vision_stream` binding this method calls is
+    /// assumed rather than present in this checkout.
+    ///
+    /// A mid-stream error is yielded as an `Err` item on the output stream,
+    /// after which the send half is closed so a producer task blocked on
+    /// `VisionFrameSender::send` observes a closed channel and stops. This
+    /// does not reconnect by itself - on error, callers should `reconnect()`
+    /// and open a fresh stream.
+    pub async fn process_vision_stream(
+        &mut self,
+    ) -> Result<(VisionFrameSender, impl Stream<Item = Result<VisionResponse, Status>>), Status> {
+        rate_limiter().await.acquire(&self.worker_id).await;
+
+        info!("📹 Opening bidirectional vision stream to The Brain...");
+
+        let (tx, rx) = mpsc::channel(VISION_STREAM_BUFFER);
+        let closed = Arc::new(AtomicBool::new(false));
+        let outbound = ClosableStream { inner: ReceiverStream::new(rx), closed: Arc::clone(&closed) };
+
+        let response = self.client.process_vision_stream(Request::new(outbound)).await?;
+        let inbound = VisionStream { inner: response.into_inner(), closed };
+
+        Ok((tx, inbound))
+    }
+
     /// Query the Hive Mind for cached experiences (with retry logic)
     /// 
     /// # Arguments
@@ -230,12 +910,19 @@ impl ChimeraClient {
         
         loop {
             attempt += 1;
-            
+
+            // Fail fast without touching the network if the breaker is tripped
+            if let Err(status) = self.breaker.admit().await {
+                warn!("⚡ Memory query short-circuited by open breaker (attempt {})", attempt);
+                return Err(status);
+            }
+
             match self.client.query_memory(request.clone()).await {
                 Ok(response) => {
                     let latency = start_time.elapsed();
                     let memory_response = response.into_inner();
-                    
+                    self.breaker.on_success().await;
+
                     debug!("🧠 Memory query completed in {:?} (attempt {})", latency, attempt);
                     
                     if !memory_response.results.is_empty() {
@@ -258,11 +945,9 @@ impl ChimeraClient {
                 }
                 Err(status) => {
                     // Check if error is retryable
-                    let is_retryable = matches!(
-                        status.code(),
-                        Code::Unavailable | Code::Internal | Code::DeadlineExceeded
-                    );
-                    
+                    let is_retryable = is_connection_error(&status);
+                    self.breaker.on_failure().await;
+
                     if !is_retryable || attempt >= max_retries {
                         let latency = start_time.elapsed();
                         error!(
@@ -309,20 +994,162 @@ impl ChimeraClient {
         }
     }
     
+    /// Build a client around an already-connected channel, bypassing `connect_to`'s
+    /// network dial and auth. Test-only: used to point a `ChimeraClient` at
+    /// `testutil::MockBrain`.
+    #[cfg(test)]
+    pub(crate) fn from_channel(channel: tonic::transport::Channel, address: String) -> Self {
+        Self {
+            client: BrainClient::with_interceptor(channel, AuthInterceptor::disabled()),
+            address,
+            congestion: Arc::new(AimdController::new(DEFAULT_INITIAL_CWND)),
+            breaker: Arc::new(CircuitBreaker::from_env()),
+            worker_id: resolve_worker_id(),
+        }
+    }
+
     /// Get the current connection address
     pub fn address(&self) -> &str {
         &self.address
     }
+
+    /// Current AIMD congestion window size for vision requests, for logging
+    /// alongside heartbeat stats
+    pub async fn congestion_window(&self) -> f64 {
+        self.congestion.cwnd().await
+    }
+
+    /// Number of vision requests currently in flight to The Brain
+    pub async fn in_flight_vision_requests(&self) -> usize {
+        self.congestion.in_flight().await
+    }
+
+    /// Current circuit breaker state, for health dashboards
+    pub async fn breaker_state(&self) -> BreakerState {
+        self.breaker.state().await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    
+    use crate::testutil::{Endpoint, MockBrainBuilder};
+
     #[tokio::test]
     #[ignore] // Requires running Brain server
     async fn test_connect_to_brain() {
         let result = ChimeraClient::connect().await;
         assert!(result.is_ok(), "Should connect to Brain");
     }
+
+    #[tokio::test]
+    async fn process_vision_retries_unavailable_then_succeeds() {
+        let mock = MockBrainBuilder::new()
+            .with_fail_once(Endpoint::ProcessVision, Code::Unavailable)
+            .serve()
+            .await;
+        let mut client = ChimeraClient::from_channel(mock.channel(), "mock://brain".into());
+
+        let result = client.process_vision(vec![0u8; 4], None).await;
+
+        assert!(result.is_ok(), "Unavailable should be retried until success");
+        assert_eq!(mock.call_log().await.len(), 2, "first call fails, second succeeds");
+    }
+
+    #[tokio::test]
+    async fn process_vision_retries_internal_and_deadline_exceeded() {
+        for code in [Code::Internal, Code::DeadlineExceeded] {
+            let mock = MockBrainBuilder::new()
+                .with_fail_once(Endpoint::ProcessVision, code)
+                .serve()
+                .await;
+            let mut client = ChimeraClient::from_channel(mock.channel(), "mock://brain".into());
+
+            let result = client.process_vision(vec![0u8; 4], None).await;
+
+            assert!(result.is_ok(), "{:?} should be retried until success", code);
+            assert_eq!(mock.call_log().await.len(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn process_vision_does_not_retry_invalid_argument() {
+        let mock = MockBrainBuilder::new()
+            .with_fail_n_times(Endpoint::ProcessVision, 5, Code::InvalidArgument)
+            .serve()
+            .await;
+        let mut client = ChimeraClient::from_channel(mock.channel(), "mock://brain".into());
+
+        let result = client.process_vision(vec![0u8; 4], None).await;
+
+        assert_eq!(result.unwrap_err().code(), Code::InvalidArgument);
+        assert_eq!(mock.call_log().await.len(), 1, "non-retryable errors return on the first attempt");
+    }
+
+    #[tokio::test]
+    async fn process_vision_gives_up_after_five_attempts() {
+        let mock = MockBrainBuilder::new()
+            .with_fail_n_times(Endpoint::ProcessVision, 10, Code::Unavailable)
+            .serve()
+            .await;
+        let mut client = ChimeraClient::from_channel(mock.channel(), "mock://brain".into());
+
+        let result = client.process_vision(vec![0u8; 4], None).await;
+
+        assert_eq!(result.unwrap_err().code(), Code::Unavailable);
+        assert_eq!(mock.call_log().await.len(), 5, "retries are capped at the hardcoded max_retries");
+    }
+
+    #[tokio::test]
+    async fn query_memory_retries_internal_then_succeeds() {
+        let mock = MockBrainBuilder::new()
+            .with_fail_once(Endpoint::QueryMemory, Code::Internal)
+            .serve()
+            .await;
+        let mut client = ChimeraClient::from_channel(mock.channel(), "mock://brain".into());
+
+        let result = client.query_memory("find the button".into(), None, None).await;
+
+        assert!(result.is_ok());
+        assert_eq!(mock.call_log().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn query_memory_does_not_retry_invalid_argument() {
+        let mock = MockBrainBuilder::new()
+            .with_fail_n_times(Endpoint::QueryMemory, 5, Code::InvalidArgument)
+            .serve()
+            .await;
+        let mut client = ChimeraClient::from_channel(mock.channel(), "mock://brain".into());
+
+        let result = client.query_memory("find the button".into(), None, None).await;
+
+        assert_eq!(result.unwrap_err().code(), Code::InvalidArgument);
+        assert_eq!(mock.call_log().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn breaker_trips_after_default_threshold_and_short_circuits() {
+        let mock = MockBrainBuilder::new()
+            .with_fail_n_times(Endpoint::ProcessVision, 10, Code::Unavailable)
+            .serve()
+            .await;
+        let mut client = ChimeraClient::from_channel(mock.channel(), "mock://brain".into());
+
+        // Exhausts the per-call retry budget (5 attempts), tripping the breaker
+        // on the 5th consecutive failure.
+        let _ = client.process_vision(vec![0u8; 4], None).await;
+        assert_eq!(client.breaker_state().await, BreakerState::Open);
+        let calls_before = mock.call_log().await.len();
+
+        // A fresh call should be short-circuited before it ever reaches the mock
+        let result = client.process_vision(vec![0u8; 4], None).await;
+
+        assert_eq!(result.unwrap_err().code(), Code::Unavailable);
+        assert_eq!(
+            mock.call_log().await.len(),
+            calls_before,
+            "short-circuited call must not reach the network"
+        );
+    }
 }
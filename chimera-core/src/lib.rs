@@ -3,12 +3,23 @@
 //! This library provides the core functionality for the Digital Phantom
 //! stealth worker swarm.
 
+pub mod auth;
+pub mod cache;
 pub mod client;
+pub mod mission;
+pub mod pool;
+pub mod session;
 pub mod stealth;
 pub mod workers;
 pub mod validation;
+#[cfg(test)]
+pub mod testutil;
 
+pub use cache::{CachedMemoryClient, MemoryCacheBackend};
 pub use client::ChimeraClient;
+pub use mission::{Mission, MissionQueue, MissionSource, ChannelMissionSource};
+pub use pool::{BrainPool, LoadBalancePolicy};
+pub use session::ResilientSession;
 pub use stealth::{DiffusionMousePath, BehavioralJitter};
 pub use workers::PhantomWorker;
 pub use validation::validate_creepjs;
\ No newline at end of file
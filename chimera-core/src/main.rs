@@ -4,20 +4,28 @@
 //! It connects to The Brain for vision processing and executes
 //! browser missions with human-like behavior.
 
+mod auth;
+mod cache;
 mod client;
+mod mission;
+mod pool;
+mod session;
+#[cfg(test)]
+mod testutil;
 mod stealth;
 mod workers;
 mod validation;
 
 use std::env;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU32, Ordering};
-use tokio::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use tokio::sync::{broadcast, mpsc, Mutex};
 use tokio::time::{sleep, Duration, interval};
 use tracing::{info, error, warn, Level};
 use tracing_subscriber::FmtSubscriber;
 
-use client::{ChimeraClient, get_brain_address, connect_with_retry};
+use client::{ChimeraClient, ClientConfig, get_brain_address, connect_with_retry};
+use mission::{ChannelMissionSource, MissionQueue};
 use stealth::{DiffusionMousePath, BehavioralJitter};
 use workers::PhantomWorker;
 use validation::validate_creepjs;
@@ -47,8 +55,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("🔗 Initializing connection to The Brain...");
     let brain_address = get_brain_address();
     info!("   Brain address: {}", brain_address);
-    
-    let mut brain_client = match connect_with_retry(5).await {
+
+    let client_config = ClientConfig::from_env();
+
+    let mut brain_client = match connect_with_retry(&client_config.reconnect_strategy).await {
         Ok(client) => {
             info!("✅ Connected to The Brain at: {}", client.address());
             client
@@ -137,32 +147,45 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Start heartbeat monitoring in background (separate task)
     let heartbeat_failures = Arc::new(AtomicU32::new(0));
     let heartbeat_failures_clone = heartbeat_failures.clone();
-    
+    let heartbeat_client_config = client_config.clone();
+
+    // Tracks whether The Brain is reachable; the mission queue pauses
+    // dispatch while this is false
+    let brain_connected = Arc::new(AtomicBool::new(true));
+    let brain_connected_clone = Arc::clone(&brain_connected);
+
     let heartbeat_handle = tokio::spawn(async move {
-        let mut interval = interval(Duration::from_secs(60));
+        let mut interval = interval(heartbeat_client_config.heartbeat_interval);
         loop {
             interval.tick().await;
-            
+
             let mut client_guard = brain_client_arc.lock().await;
             match client_guard.health_check().await {
                 Ok(_) => {
-                    info!("💓 Heartbeat: The Brain is healthy");
+                    info!(
+                        "💓 Heartbeat: The Brain is healthy (cwnd: {:.1}, in-flight: {})",
+                        client_guard.congestion_window().await,
+                        client_guard.in_flight_vision_requests().await
+                    );
                     heartbeat_failures_clone.store(0, Ordering::Relaxed);
+                    brain_connected_clone.store(true, Ordering::Relaxed);
                 }
                 Err(e) => {
                     let failures = heartbeat_failures_clone.fetch_add(1, Ordering::Relaxed) + 1;
                     warn!("⚠️ Heartbeat failed (consecutive failures: {}): {}", failures, e);
-                    
+
                     if failures >= 3 {
                         error!("🚨 CRITICAL: Heartbeat failed 3 consecutive times!");
                         error!("   Attempting full reconnection...");
-                        
+                        brain_connected_clone.store(false, Ordering::Relaxed);
+
                         // Attempt reconnection
-                        match connect_with_retry(5).await {
+                        match connect_with_retry(&heartbeat_client_config.reconnect_strategy).await {
                             Ok(new_client) => {
                                 *client_guard = new_client;
                                 info!("✅ Reconnected to The Brain after heartbeat failures");
                                 heartbeat_failures_clone.store(0, Ordering::Relaxed);
+                                brain_connected_clone.store(true, Ordering::Relaxed);
                             }
                             Err(e) => {
                                 error!("❌ Reconnection failed: {}", e);
@@ -174,7 +197,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
     });
-    
+
     // Create phantom worker with shared brain client
     let mut phantom_worker = PhantomWorker::new(webdriver_client);
     phantom_worker.set_brain_client_arc(brain_client_for_worker);
@@ -208,6 +231,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
     
+    // 4. Launch the mission queue's background event-processing loop
+    let (mission_tx, mission_rx) = mpsc::channel(100);
+    let (shutdown_tx, _) = broadcast::channel::<()>(1);
+
+    let phantom_worker_arc = Arc::new(Mutex::new(phantom_worker));
+    let mission_queue = MissionQueue::new(Box::new(ChannelMissionSource::new(mission_rx)));
+    let mission_shutdown_rx = shutdown_tx.subscribe();
+    let mission_worker_arc = Arc::clone(&phantom_worker_arc);
+
+    let mission_queue_handle = tokio::spawn(
+        mission_queue.run(mission_worker_arc, Arc::clone(&brain_connected), mission_shutdown_rx),
+    );
+
+    // Keep a sender around so in-process callers (gRPC handlers, REPL, etc.)
+    // can feed missions in; unused today but this is the first MissionSource
+    let _mission_tx = mission_tx;
+
     info!("");
     info!("🎯 The Body is ready for missions!");
     info!("   - Vision Service: Connected");
@@ -216,24 +256,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("   - Browser Automation: Operational");
     info!("   - Stealth Validation: 100% Human");
     info!("   - gRPC Resilience: Active (retry + heartbeat)");
+    info!("   - Mission Queue: Active (in-process channel)");
     info!("");
-    info!("💓 Heartbeat monitoring active (every 60 seconds)");
+    info!("💓 Heartbeat monitoring active (every {:?})", client_config.heartbeat_interval);
     info!("   Worker will continue running for missions...");
     info!("   Press Ctrl+C to stop");
     info!("");
-    
-    // Keep the worker running (heartbeat continues in background)
-    // In production, this would wait for mission queue or signals
+
+    // Keep the worker running (heartbeat and mission queue continue in background)
     tokio::signal::ctrl_c().await?;
-    
+
     info!("🛑 Shutting down...");
-    
-    // Stop heartbeat monitoring
+
+    // Signal the mission queue to stop and wait for any mission in progress
+    // to finish, then stop heartbeat monitoring
+    let _ = shutdown_tx.send(());
+    let _ = mission_queue_handle.await;
     heartbeat_handle.abort();
     
     // Close browser session
     info!("🔒 Closing Phantom Worker");
-    phantom_worker.close().await?;
+    phantom_worker_arc.lock().await.close().await?;
     
     info!("✅ Shutdown complete");
     
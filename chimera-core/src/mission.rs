@@ -0,0 +1,139 @@
+//! Mission Queue & Background Event Loop
+//!
+//! Once a `PhantomWorker` clears CreepJS validation it needs a way to
+//! actually receive and execute work. `Mission` defines what a worker can be
+//! asked to do, `MissionSource` is a pluggable origin for missions (today an
+//! in-process channel; later gRPC or a local file), and `MissionQueue` is the
+//! `select!`-driven loop that pulls from a source and dispatches to a worker.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use tokio::sync::{broadcast, mpsc, Mutex};
+use tokio::time::{interval, Duration};
+use tracing::{debug, error, info};
+
+use crate::workers::PhantomWorker;
+
+/// A unit of work dispatched to a `PhantomWorker`
+#[derive(Debug, Clone)]
+pub enum Mission {
+    /// Navigate the browser to a URL
+    Navigate { url: String },
+    /// Click an element identified by a selector (or Brain-provided coordinates)
+    Click { selector: String },
+    /// Type text into an element identified by a selector
+    TypeText { selector: String, text: String },
+    /// Scrape content from an element identified by a selector
+    Scrape { selector: String },
+}
+
+/// A pluggable origin for `Mission`s. The in-process channel is the first
+/// implementation; gRPC and local-file sources can implement this trait
+/// without the event loop changing.
+pub trait MissionSource: Send {
+    /// Wait for and return the next mission, or `None` once the source is exhausted
+    fn next_mission(&mut self) -> Pin<Box<dyn Future<Output = Option<Mission>> + Send + '_>>;
+}
+
+/// Mission source backed by an in-process `tokio::sync::mpsc` channel
+pub struct ChannelMissionSource {
+    receiver: mpsc::Receiver<Mission>,
+}
+
+impl ChannelMissionSource {
+    pub fn new(receiver: mpsc::Receiver<Mission>) -> Self {
+        Self { receiver }
+    }
+}
+
+impl MissionSource for ChannelMissionSource {
+    fn next_mission(&mut self) -> Pin<Box<dyn Future<Output = Option<Mission>> + Send + '_>> {
+        Box::pin(async move { self.receiver.recv().await })
+    }
+}
+
+/// Background event-processing loop that pulls missions from a `MissionSource`
+/// and dispatches them to a `PhantomWorker`
+pub struct MissionQueue {
+    source: Box<dyn MissionSource>,
+}
+
+impl MissionQueue {
+    pub fn new(source: Box<dyn MissionSource>) -> Self {
+        Self { source }
+    }
+
+    /// Run the event loop until `shutdown` fires or the source is exhausted
+    ///
+    /// Driven by `select!` over four signals:
+    /// * the mission source, for incoming work
+    /// * `brain_connected`, which pauses dispatch (without dropping missions)
+    ///   while the heartbeat considers The Brain unreachable
+    /// * a periodic checkpoint tick, which is only logged; no queue or
+    ///   progress state is persisted
+    /// * `shutdown`, which stops pulling new missions and lets the loop exit
+    ///
+    /// A mission already being dispatched runs to completion before `shutdown`
+    /// is seen; missions still waiting in the source are not dispatched.
+    pub async fn run(
+        mut self,
+        worker: Arc<Mutex<PhantomWorker>>,
+        brain_connected: Arc<AtomicBool>,
+        mut shutdown: broadcast::Receiver<()>,
+    ) {
+        let mut checkpoint = interval(Duration::from_secs(30));
+
+        info!("📋 Mission queue event loop started");
+
+        loop {
+            if !brain_connected.load(Ordering::Relaxed) {
+                // Heartbeat reports The Brain unreachable - hold off dispatching
+                // new missions, but stay responsive to checkpoint/shutdown.
+                tokio::select! {
+                    _ = checkpoint.tick() => {
+                        debug!("💾 Checkpoint tick (dispatch paused, Brain unreachable)");
+                    }
+                    _ = shutdown.recv() => {
+                        info!("🛑 Shutdown received while paused, stopping...");
+                        break;
+                    }
+                    _ = tokio::time::sleep(Duration::from_millis(250)) => {}
+                }
+                continue;
+            }
+
+            tokio::select! {
+                mission = self.source.next_mission() => {
+                    match mission {
+                        Some(mission) => self.dispatch(&worker, mission).await,
+                        None => {
+                            info!("📭 Mission source exhausted, stopping event loop");
+                            break;
+                        }
+                    }
+                }
+                _ = checkpoint.tick() => {
+                    debug!("💾 Checkpoint tick");
+                }
+                _ = shutdown.recv() => {
+                    info!("🛑 Shutdown received, stopping mission queue...");
+                    break;
+                }
+            }
+        }
+
+        info!("✅ Mission queue event loop stopped");
+    }
+
+    async fn dispatch(&self, worker: &Arc<Mutex<PhantomWorker>>, mission: Mission) {
+        info!("🎯 Dispatching mission: {:?}", mission);
+
+        let mut worker = worker.lock().await;
+        if let Err(e) = worker.execute_mission(mission).await {
+            error!("❌ Mission failed: {}", e);
+        }
+    }
+}
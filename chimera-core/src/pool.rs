@@ -0,0 +1,214 @@
+//! Client-side connection pool across multiple Brain replicas
+//!
+//! `get_brain_addresses` may resolve to more than one endpoint; `BrainPool`
+//! keeps one `ChimeraClient` per replica (so each replica's `CircuitBreaker`
+//! and AIMD window stay isolated) and picks which one serves each call via a
+//! `LoadBalancePolicy`. A replica whose breaker has tripped open is skipped by
+//! ordinary traffic; a background task keeps nudging ejected replicas with
+//! `health_check` so the breaker's own cooldown/half-open recovery eventually
+//! re-admits them without the pool needing separate ejection bookkeeping.
+
+use std::env;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+use tokio::time::interval;
+use tonic::Status;
+use tracing::{debug, info};
+
+use crate::client::chimera::{MemoryResponse, VisionResponse};
+use crate::client::{env_u64, get_brain_addresses, BreakerState, ChimeraClient};
+
+/// How a `BrainPool` picks which healthy replica serves the next call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadBalancePolicy {
+    /// Cycle through healthy replicas in order
+    RoundRobin,
+    /// Send each call to the healthy replica with the lowest smoothed latency
+    LeastLatency,
+}
+
+impl LoadBalancePolicy {
+    /// Build from `CHIMERA_LB_POLICY`: `round_robin` (default) or `least_latency`
+    pub fn from_env() -> Self {
+        match env::var("CHIMERA_LB_POLICY").as_deref() {
+            Ok("least_latency") => LoadBalancePolicy::LeastLatency,
+            _ => LoadBalancePolicy::RoundRobin,
+        }
+    }
+}
+
+/// Smoothing factor for a replica's latency EWMA
+const LATENCY_ALPHA: f64 = 0.25;
+
+/// One pooled replica: its own `ChimeraClient` (connection, breaker, AIMD
+/// window) plus a smoothed latency estimate for the least-latency policy
+struct Replica {
+    client: Mutex<ChimeraClient>,
+    latency_ewma_ms: Mutex<Option<f64>>,
+}
+
+impl Replica {
+    /// Admits ordinary traffic unless the breaker has tripped fully open;
+    /// `HalfOpen` is left alone since the breaker already gates it to one probe
+    async fn is_healthy(&self) -> bool {
+        !matches!(self.client.lock().await.breaker_state().await, BreakerState::Open)
+    }
+
+    async fn record_latency(&self, elapsed: Duration) {
+        let sample_ms = elapsed.as_secs_f64() * 1000.0;
+        let mut ewma = self.latency_ewma_ms.lock().await;
+        *ewma = Some(match *ewma {
+            Some(prev) => prev + LATENCY_ALPHA * (sample_ms - prev),
+            None => sample_ms,
+        });
+    }
+
+    async fn latency_ewma_ms(&self) -> f64 {
+        self.latency_ewma_ms.lock().await.unwrap_or(0.0)
+    }
+}
+
+/// Pool of `ChimeraClient`s spread across every configured Brain replica,
+/// load-balancing `process_vision`/`query_memory` calls among the healthy ones
+/// so a single replica failing doesn't take the whole worker down with it.
+pub struct BrainPool {
+    replicas: Vec<Arc<Replica>>,
+    policy: LoadBalancePolicy,
+    next: AtomicUsize,
+    reprobe_handle: tokio::task::JoinHandle<()>,
+}
+
+impl BrainPool {
+    /// Connect to every address in `get_brain_addresses()`, using the policy
+    /// from `CHIMERA_LB_POLICY`
+    pub async fn connect() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::connect_to(&get_brain_addresses(), LoadBalancePolicy::from_env()).await
+    }
+
+    /// Connect to a specific set of addresses with a specific policy
+    pub async fn connect_to(
+        addresses: &[String],
+        policy: LoadBalancePolicy,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        if addresses.is_empty() {
+            return Err("BrainPool requires at least one Brain address".into());
+        }
+
+        let mut replicas = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            let client = ChimeraClient::connect_to(address).await?;
+            replicas.push(Arc::new(Replica {
+                client: Mutex::new(client),
+                latency_ewma_ms: Mutex::new(None),
+            }));
+        }
+
+        info!("🌐 Brain pool connected to {} replica(s), policy: {:?}", replicas.len(), policy);
+
+        let reprobe_replicas = replicas.clone();
+        let reprobe_interval = Duration::from_secs(env_u64("CHIMERA_LB_REPROBE_INTERVAL_SECS", 15));
+        let reprobe_handle = tokio::spawn(Self::reprobe_loop(reprobe_replicas, reprobe_interval));
+
+        Ok(Self { replicas, policy, next: AtomicUsize::new(0), reprobe_handle })
+    }
+
+    /// Periodically nudge every replica with `health_check`, including ones
+    /// the breaker has ejected - a healthy call there is exactly what the
+    /// breaker's own half-open recovery is waiting to observe
+    async fn reprobe_loop(replicas: Vec<Arc<Replica>>, period: Duration) {
+        let mut ticker = interval(period);
+        loop {
+            ticker.tick().await;
+            for replica in &replicas {
+                let mut client = replica.client.lock().await;
+                if let Err(e) = client.health_check().await {
+                    debug!("🩺 Brain pool re-probe failed for {}: {}", client.address(), e);
+                }
+            }
+        }
+    }
+
+    /// Pick a replica per `self.policy`, restricted to healthy ones if any
+    /// exist; falls back to the full set rather than failing a call outright
+    /// if every replica is currently ejected
+    async fn pick(&self) -> Arc<Replica> {
+        let mut healthy = Vec::with_capacity(self.replicas.len());
+        for replica in &self.replicas {
+            if replica.is_healthy().await {
+                healthy.push(Arc::clone(replica));
+            }
+        }
+        let candidates = if healthy.is_empty() { &self.replicas } else { &healthy };
+
+        match self.policy {
+            LoadBalancePolicy::RoundRobin => {
+                let i = self.next.fetch_add(1, Ordering::Relaxed) % candidates.len();
+                Arc::clone(&candidates[i])
+            }
+            LoadBalancePolicy::LeastLatency => {
+                let mut best = Arc::clone(&candidates[0]);
+                let mut best_latency = best.latency_ewma_ms().await;
+                for candidate in &candidates[1..] {
+                    let latency = candidate.latency_ewma_ms().await;
+                    if latency < best_latency {
+                        best_latency = latency;
+                        best = Arc::clone(candidate);
+                    }
+                }
+                best
+            }
+        }
+    }
+
+    /// Process a screenshot via a healthy replica, selected per `self.policy`
+    pub async fn process_vision(
+        &self,
+        screenshot: Vec<u8>,
+        text_command: Option<String>,
+    ) -> Result<VisionResponse, Status> {
+        let replica = self.pick().await;
+        let start = Instant::now();
+        let result = replica.client.lock().await.process_vision(screenshot, text_command).await;
+        replica.record_latency(start.elapsed()).await;
+        result
+    }
+
+    /// Query the Hive Mind via a healthy replica, selected per `self.policy`
+    pub async fn query_memory(
+        &self,
+        query: String,
+        ax_tree_summary: Option<String>,
+        screenshot_hash: Option<String>,
+    ) -> Result<MemoryResponse, Status> {
+        let replica = self.pick().await;
+        let start = Instant::now();
+        let result = replica.client.lock().await.query_memory(query, ax_tree_summary, screenshot_hash).await;
+        replica.record_latency(start.elapsed()).await;
+        result
+    }
+
+    /// Number of replicas currently admitting ordinary traffic (breaker not `Open`)
+    pub async fn healthy_replica_count(&self) -> usize {
+        let mut count = 0;
+        for replica in &self.replicas {
+            if replica.is_healthy().await {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Total number of replicas in the pool, healthy or ejected
+    pub fn replica_count(&self) -> usize {
+        self.replicas.len()
+    }
+}
+
+impl Drop for BrainPool {
+    fn drop(&mut self) {
+        self.reprobe_handle.abort();
+    }
+}
@@ -0,0 +1,270 @@
+//! Resilient Session - durable request queue with reconnect-and-replay
+//!
+//! `ChimeraClient::reconnect` swaps the channel but drops any in-flight or
+//! pending work; callers must retry manually. `ResilientSession` is an
+//! optional wrapper, analogous to how `MissionQueue` wraps dispatch to a
+//! `PhantomWorker`: it owns a `ChimeraClient` plus a bounded FIFO queue, and a
+//! background task drains the queue in order. A connection-level failure
+//! reconnects (via the existing `ReconnectStrategy`) before replaying the
+//! failed request and everything still queued behind it, so callers see their
+//! request eventually satisfied instead of erroring out on a transient outage.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::sleep;
+use tonic::Status;
+use tracing::{error, info, warn};
+
+use crate::client::chimera::{MemoryResponse, VisionResponse};
+use crate::client::{env_u64, is_connection_error, ChimeraClient, ReconnectStrategy};
+
+/// A unit of queued work: a vision or memory request plus the oneshot channel
+/// used to deliver its eventual result back to the original caller
+enum PendingRequest {
+    Vision {
+        screenshot: Vec<u8>,
+        text_command: Option<String>,
+        reply: oneshot::Sender<Result<VisionResponse, Status>>,
+    },
+    Memory {
+        query: String,
+        ax_tree_summary: Option<String>,
+        screenshot_hash: Option<String>,
+        reply: oneshot::Sender<Result<MemoryResponse, Status>>,
+    },
+}
+
+impl PendingRequest {
+    /// Attempt this request once. Delivers a terminal result (success, or a
+    /// non-connection-level error) to `reply` and returns `Ok(())`. On a
+    /// connection-level error the result is withheld and `self` is handed
+    /// back so the caller can reconnect and try again.
+    async fn try_once(self, client: &mut ChimeraClient) -> Result<(), PendingRequest> {
+        match self {
+            PendingRequest::Vision { screenshot, text_command, reply } => {
+                match client.process_vision(screenshot.clone(), text_command.clone()).await {
+                    Err(status) if is_connection_error(&status) => {
+                        Err(PendingRequest::Vision { screenshot, text_command, reply })
+                    }
+                    result => {
+                        let _ = reply.send(result);
+                        Ok(())
+                    }
+                }
+            }
+            PendingRequest::Memory { query, ax_tree_summary, screenshot_hash, reply } => {
+                match client.query_memory(query.clone(), ax_tree_summary.clone(), screenshot_hash.clone()).await {
+                    Err(status) if is_connection_error(&status) => {
+                        Err(PendingRequest::Memory { query, ax_tree_summary, screenshot_hash, reply })
+                    }
+                    result => {
+                        let _ = reply.send(result);
+                        Ok(())
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A `ChimeraClient` wrapped with a bounded, order-preserving request queue and
+/// a background task that owns the connection and replays queued work across
+/// reconnects
+pub struct ResilientSession {
+    sender: mpsc::Sender<PendingRequest>,
+    depth: Arc<AtomicUsize>,
+    worker: tokio::task::JoinHandle<()>,
+}
+
+impl ResilientSession {
+    /// Wrap `client` with a queue of `queue_capacity` pending requests, reconnecting
+    /// per `strategy` when a connection-level error is hit while draining it
+    pub fn spawn(client: ChimeraClient, strategy: ReconnectStrategy, queue_capacity: usize) -> Self {
+        let (sender, receiver) = mpsc::channel(queue_capacity);
+        let depth = Arc::new(AtomicUsize::new(0));
+        let worker = tokio::spawn(Self::run(client, strategy, receiver, Arc::clone(&depth)));
+
+        Self { sender, depth, worker }
+    }
+
+    /// Build from environment variables:
+    /// * `CHIMERA_RESILIENT_QUEUE_CAPACITY` - bounded queue depth (default: 100)
+    pub fn from_env(client: ChimeraClient) -> Self {
+        let capacity = env_u64("CHIMERA_RESILIENT_QUEUE_CAPACITY", 100) as usize;
+        Self::spawn(client, ReconnectStrategy::from_env(), capacity)
+    }
+
+    /// Number of requests currently buffered (queued, plus the one in flight or
+    /// being replayed, plus callers still waiting for space in a full queue),
+    /// so operators can observe backlog building up during a Brain outage
+    pub fn queue_depth(&self) -> usize {
+        self.depth.load(Ordering::Relaxed)
+    }
+
+    /// Queue a vision request and await its eventual result. Blocks (applying
+    /// backpressure) if the queue is full rather than dropping work.
+    pub async fn process_vision(
+        &self,
+        screenshot: Vec<u8>,
+        text_command: Option<String>,
+    ) -> Result<VisionResponse, Status> {
+        let (reply, result) = oneshot::channel();
+        self.enqueue(PendingRequest::Vision { screenshot, text_command, reply }).await?;
+        Self::await_reply(result).await
+    }
+
+    /// Queue a memory query and await its eventual result. Blocks (applying
+    /// backpressure) if the queue is full rather than dropping work.
+    pub async fn query_memory(
+        &self,
+        query: String,
+        ax_tree_summary: Option<String>,
+        screenshot_hash: Option<String>,
+    ) -> Result<MemoryResponse, Status> {
+        let (reply, result) = oneshot::channel();
+        self.enqueue(PendingRequest::Memory { query, ax_tree_summary, screenshot_hash, reply }).await?;
+        Self::await_reply(result).await
+    }
+
+    async fn enqueue(&self, request: PendingRequest) -> Result<(), Status> {
+        self.depth.fetch_add(1, Ordering::Relaxed);
+        if self.sender.send(request).await.is_err() {
+            self.depth.fetch_sub(1, Ordering::Relaxed);
+            return Err(Status::unavailable("resilient session worker has shut down"));
+        }
+        Ok(())
+    }
+
+    async fn await_reply<T>(result: oneshot::Receiver<Result<T, Status>>) -> Result<T, Status> {
+        result
+            .await
+            .unwrap_or_else(|_| Err(Status::cancelled("resilient session worker dropped the request before replying")))
+    }
+
+    /// Background task: drains the queue in order, reconnecting and replaying
+    /// in place whenever a connection-level error is hit
+    async fn run(
+        mut client: ChimeraClient,
+        strategy: ReconnectStrategy,
+        mut receiver: mpsc::Receiver<PendingRequest>,
+        depth: Arc<AtomicUsize>,
+    ) {
+        info!("📦 Resilient session worker started");
+
+        while let Some(mut request) = receiver.recv().await {
+            loop {
+                match request.try_once(&mut client).await {
+                    Ok(()) => break,
+                    Err(retry) => {
+                        warn!("⚠️ Resilient session hit a connection-level error, reconnecting before replay...");
+                        Self::reconnect_until_success(&mut client, &strategy).await;
+                        request = retry;
+                    }
+                }
+            }
+            depth.fetch_sub(1, Ordering::Relaxed);
+        }
+
+        info!("📪 Resilient session worker stopped (queue closed)");
+    }
+
+    /// Reconnect using `strategy`'s backoff, retrying indefinitely - buffered
+    /// work is only dropped if the process exits, never given up on here
+    async fn reconnect_until_success(client: &mut ChimeraClient, strategy: &ReconnectStrategy) {
+        let max_attempts = strategy.max_attempts();
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            match client.reconnect().await {
+                Ok(()) => {
+                    info!("✅ Resilient session reconnected after {} attempt(s)", attempt);
+                    return;
+                }
+                Err(e) => {
+                    error!("❌ Resilient session reconnect attempt {} failed: {}", attempt, e);
+                    let delay = strategy.delay_for_attempt(attempt.min(max_attempts));
+                    sleep(delay).await;
+                }
+            }
+        }
+    }
+}
+
+impl Drop for ResilientSession {
+    fn drop(&mut self) {
+        self.worker.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ChimeraClient;
+    use crate::testutil::MockBrainBuilder;
+    use std::time::Duration as StdDuration;
+
+    #[tokio::test]
+    async fn process_vision_round_trips_through_the_queue() {
+        let mock = MockBrainBuilder::new().serve().await;
+        let client = ChimeraClient::from_channel(mock.channel(), "mock://brain".into());
+        let session = ResilientSession::spawn(client, ReconnectStrategy::default(), 8);
+
+        let result = session.process_vision(vec![0u8; 4], None).await;
+
+        assert!(result.is_ok());
+        assert_eq!(session.queue_depth(), 0);
+    }
+
+    #[tokio::test]
+    async fn query_memory_round_trips_through_the_queue() {
+        let mock = MockBrainBuilder::new().serve().await;
+        let client = ChimeraClient::from_channel(mock.channel(), "mock://brain".into());
+        let session = ResilientSession::spawn(client, ReconnectStrategy::default(), 8);
+
+        let result = session.query_memory("find the button".into(), None, None).await;
+
+        assert!(result.is_ok());
+        assert_eq!(session.queue_depth(), 0);
+    }
+
+    #[tokio::test]
+    async fn queue_depth_reflects_buffered_work_while_in_flight() {
+        let mock = MockBrainBuilder::new()
+            .with_latency(crate::testutil::Endpoint::ProcessVision, StdDuration::from_millis(100))
+            .serve()
+            .await;
+        let client = ChimeraClient::from_channel(mock.channel(), "mock://brain".into());
+        let session = ResilientSession::spawn(client, ReconnectStrategy::default(), 8);
+
+        let (reply, pending) = oneshot::channel();
+        session
+            .enqueue(PendingRequest::Vision { screenshot: vec![0u8; 4], text_command: None, reply })
+            .await
+            .unwrap();
+
+        // The worker has picked the request up but the mock hasn't replied yet
+        sleep(StdDuration::from_millis(20)).await;
+        assert_eq!(session.queue_depth(), 1);
+
+        pending.await.unwrap().unwrap();
+        assert_eq!(session.queue_depth(), 0);
+    }
+
+    #[tokio::test]
+    async fn non_connection_error_is_delivered_without_reconnecting() {
+        let mock = MockBrainBuilder::new()
+            .with_fail_n_times(crate::testutil::Endpoint::ProcessVision, 5, tonic::Code::InvalidArgument)
+            .serve()
+            .await;
+        let client = ChimeraClient::from_channel(mock.channel(), "mock://brain".into());
+        let session = ResilientSession::spawn(client, ReconnectStrategy::default(), 8);
+
+        let result = session.process_vision(vec![0u8; 4], None).await;
+
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+        assert_eq!(mock.call_log().await.len(), 1, "non-retryable errors surface on the first attempt");
+    }
+}
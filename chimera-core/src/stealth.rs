@@ -8,10 +8,15 @@ use noise::{NoiseFn, Perlin};
 use std::time::Duration;
 use tracing::debug;
 
+/// Default Fitts's-law target width (px) used to size ballistic movement time
+const DEFAULT_TARGET_WIDTH: f64 = 40.0;
+
 /// Mouse path generator using diffusion-based movement
 pub struct DiffusionMousePath {
     perlin: Perlin,
     seed: u32,
+    ballistic: bool,
+    target_width: f64,
 }
 
 impl DiffusionMousePath {
@@ -19,15 +24,31 @@ impl DiffusionMousePath {
     pub fn new() -> Self {
         let mut rng = rand::thread_rng();
         let seed = rng.gen();
-        
+
         Self {
             perlin: Perlin::new(seed),
             seed,
+            ballistic: false,
+            target_width: DEFAULT_TARGET_WIDTH,
         }
     }
-    
+
+    /// Enable the ballistic overshoot-and-correct movement model (see
+    /// `generate_path`) in place of the default single symmetric ease
+    pub fn with_ballistic_movement(mut self) -> Self {
+        self.ballistic = true;
+        self
+    }
+
+    /// Set the Fitts's-law target width (px) used to size ballistic
+    /// movement time; only relevant when ballistic movement is enabled
+    pub fn with_target_width(mut self, target_width: f64) -> Self {
+        self.target_width = target_width;
+        self
+    }
+
     /// Generate a human-like mouse path from start to end
-    /// 
+    ///
     /// Uses Perlin noise to create natural-looking curves and
     /// micro-movements that mimic real human behavior.
     /// 
@@ -44,9 +65,13 @@ impl DiffusionMousePath {
         end: (f64, f64),
         steps: usize,
     ) -> Vec<(f64, f64, u64)> {
+        if self.ballistic {
+            return self.generate_ballistic_path(start, end, steps);
+        }
+
         let mut path = Vec::with_capacity(steps + 1);
         let mut rng = rand::thread_rng();
-        
+
         // Calculate total distance for speed variation
         let dx = end.0 - start.0;
         let dy = end.1 - start.1;
@@ -105,7 +130,130 @@ impl DiffusionMousePath {
             path.len(),
             distance
         );
-        
+
+        path
+    }
+
+    /// Ballistic movement model: a fast primary submovement that
+    /// deliberately overshoots the target by a small Fitts's-law-derived
+    /// distance, followed by one or two slower corrective submovements that
+    /// home in on the final point. This mirrors how humans actually reach
+    /// for a target (overshoot-and-correct) rather than gliding to it with
+    /// perfect symmetric easing, which advanced behavioral fingerprinting
+    /// can flag as non-human.
+    ///
+    /// Output format matches `generate_path`: `(x, y, delay_ms)` per step.
+    fn generate_ballistic_path(
+        &self,
+        start: (f64, f64),
+        end: (f64, f64),
+        steps: usize,
+    ) -> Vec<(f64, f64, u64)> {
+        let mut rng = rand::thread_rng();
+
+        let dx = end.0 - start.0;
+        let dy = end.1 - start.1;
+        let distance = (dx * dx + dy * dy).sqrt();
+
+        if distance < 1.0 {
+            return vec![(end.0, end.1, 10)];
+        }
+
+        // Fitts's law: MT = a + b * log2(distance / target_width + 1)
+        const FITTS_A_MS: f64 = 50.0;
+        const FITTS_B_MS: f64 = 150.0;
+        let total_time_ms = FITTS_A_MS + FITTS_B_MS * (distance / self.target_width + 1.0).log2();
+
+        // Primary submovement deliberately overshoots by 2-8% of the distance
+        let overshoot_fraction = rng.gen_range(0.02..0.08);
+        let dir = (dx / distance, dy / distance);
+        let overshoot_point = (
+            start.0 + dir.0 * distance * (1.0 + overshoot_fraction),
+            start.1 + dir.1 * distance * (1.0 + overshoot_fraction),
+        );
+
+        // One or two corrective submovements home in on the real target
+        let correction_count = if rng.gen_bool(0.6) { 1 } else { 2 };
+
+        let mut waypoints = vec![start, overshoot_point];
+        let mut current = overshoot_point;
+        for i in 0..correction_count {
+            let next = if i == correction_count - 1 {
+                end
+            } else {
+                // An intermediate correction closes most, not all, of the remaining gap
+                (
+                    current.0 + (end.0 - current.0) * 0.7,
+                    current.1 + (end.1 - current.1) * 0.7,
+                )
+            };
+            waypoints.push(next);
+            current = next;
+        }
+
+        // Split the step budget across submovements proportional to leg length
+        let leg_lengths: Vec<f64> = waypoints
+            .windows(2)
+            .map(|w| ((w[1].0 - w[0].0).powi(2) + (w[1].1 - w[0].1).powi(2)).sqrt())
+            .collect();
+        let total_length: f64 = leg_lengths.iter().sum::<f64>().max(1.0);
+
+        // Split the movement time by length too, but weight corrective legs
+        // more heavily per pixel so they move slower than the primary leg
+        const CORRECTION_SLOWDOWN: f64 = 3.0;
+        let leg_time_weights: Vec<f64> = leg_lengths
+            .iter()
+            .enumerate()
+            .map(|(i, len)| if i == 0 { *len } else { len * CORRECTION_SLOWDOWN })
+            .collect();
+        let total_time_weight: f64 = leg_time_weights.iter().sum::<f64>().max(1.0);
+
+        let mut path = Vec::with_capacity(steps + 1);
+        path.push((start.0, start.1, 0));
+
+        for (leg_idx, window) in waypoints.windows(2).enumerate() {
+            let (leg_start, leg_end) = (window[0], window[1]);
+            let leg_fraction = leg_lengths[leg_idx] / total_length;
+            let leg_steps = ((steps as f64 * leg_fraction).round() as usize).max(1);
+            let leg_time_ms = total_time_ms * leg_time_weights[leg_idx] / total_time_weight;
+
+            for i in 1..=leg_steps {
+                let t = i as f64 / leg_steps as f64;
+
+                // Asymmetric bell: position 6t^2 - 8t^3 + 3t^4 has speed
+                // 12t(1-t)^2, peaking a third of the way along the leg (rapid
+                // acceleration) and then tailing off slowly (long deceleration)
+                let eased_t = t * t * (6.0 - 8.0 * t + 3.0 * t * t);
+
+                let mut x = leg_start.0 + (leg_end.0 - leg_start.0) * eased_t;
+                let mut y = leg_start.1 + (leg_end.1 - leg_start.1) * eased_t;
+
+                let noise_amplitude = leg_lengths[leg_idx] * 0.03;
+                let noise_t = leg_idx as f64 + t;
+                x += self.perlin.get([noise_t * 10.0, 0.0]) * noise_amplitude;
+                y += self.perlin.get([noise_t * 10.0, 1.0]) * noise_amplitude;
+
+                x += rng.gen_range(-1.0..1.0);
+                y += rng.gen_range(-1.0..1.0);
+
+                // Step-to-step delay is inversely proportional to instantaneous
+                // speed, so it is longest at the slow start and slow end of each leg
+                let velocity_factor = (12.0 * t * (1.0 - t).powi(2)).max(0.05);
+                let base_delay = (leg_time_ms / leg_steps as f64) / velocity_factor;
+                let delay_ms = (base_delay * rng.gen_range(0.85..1.15)).max(1.0) as u64;
+
+                path.push((x, y, delay_ms));
+            }
+        }
+
+        debug!(
+            "Generated ballistic path: {} points, {:.0}px distance, {:.0}ms Fitts MT, {} correction(s)",
+            path.len(),
+            distance,
+            total_time_ms,
+            correction_count
+        );
+
         path
     }
 }
@@ -222,7 +370,27 @@ mod tests {
         assert!((last.0 - 100.0).abs() < 10.0);
         assert!((last.1 - 100.0).abs() < 10.0);
     }
-    
+
+    #[test]
+    fn test_ballistic_path_reaches_target() {
+        let generator = DiffusionMousePath::new().with_ballistic_movement();
+        let path = generator.generate_path((0.0, 0.0), (300.0, 150.0), 40);
+
+        // First point should be exactly the start
+        assert_eq!(path[0].0, 0.0);
+        assert_eq!(path[0].1, 0.0);
+
+        // Last point should land on the actual target, not the overshoot point
+        let last = path.last().unwrap();
+        assert!((last.0 - 300.0).abs() < 5.0);
+        assert!((last.1 - 150.0).abs() < 5.0);
+
+        // Some intermediate point should have overshot past the target along
+        // the direction of travel, proving the ballistic decomposition ran
+        let overshot = path.iter().any(|p| p.0 > 300.0);
+        assert!(overshot, "Expected at least one point past the target (overshoot)");
+    }
+
     #[test]
     fn test_behavioral_jitter() {
         let mut jitter = BehavioralJitter::new();
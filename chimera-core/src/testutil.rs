@@ -0,0 +1,236 @@
+//! In-process mock of The Brain's gRPC service, for exercising
+//! `ChimeraClient`'s retry/backoff logic without a live Python Brain.
+//!
+//! `MockBrain` implements the generated `chimera::brain_server::Brain` trait
+//! and is served over an in-memory duplex stream rather than a real socket,
+//! so tests can script exact failure sequences and assert on attempt counts
+//! instead of hoping a flaky external server reproduces them.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tonic::transport::{Channel, Endpoint as TransportEndpoint, Server, Uri};
+use tonic::{async_trait, Code, Request, Response, Status};
+
+use crate::client::chimera::brain_server::{Brain, BrainServer};
+use crate::client::chimera::{MemoryResponse, ProcessVisionRequest, QueryMemoryRequest, VisionResponse};
+
+/// Which RPC a fault script or canned response applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endpoint {
+    ProcessVision,
+    QueryMemory,
+}
+
+/// One RPC invocation recorded by `MockBrain`, for asserting exact attempt counts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallKind {
+    ProcessVision,
+    QueryMemory,
+}
+
+/// Scripted behavior for a single RPC: fail with `fail_code` on the next
+/// `remaining_failures` calls (after an injected `latency`), then return the
+/// canned `response`
+struct FaultScript<T> {
+    remaining_failures: u32,
+    fail_code: Code,
+    latency: Duration,
+    response: T,
+}
+
+impl<T: Default> Default for FaultScript<T> {
+    fn default() -> Self {
+        Self {
+            remaining_failures: 0,
+            fail_code: Code::Unavailable,
+            latency: Duration::ZERO,
+            response: T::default(),
+        }
+    }
+}
+
+impl<T> FaultScript<T> {
+    /// Consume one scripted failure, if any remain
+    fn take_failure(&mut self) -> Option<Code> {
+        if self.remaining_failures > 0 {
+            self.remaining_failures -= 1;
+            Some(self.fail_code)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Default)]
+struct MockState {
+    call_log: Vec<CallKind>,
+    vision: FaultScript<VisionResponse>,
+    memory: FaultScript<MemoryResponse>,
+}
+
+/// Builder for a scripted `MockBrain`. Configure fault scripts and canned
+/// responses, then `serve()` to spin it up behind an in-process channel.
+#[derive(Default)]
+pub struct MockBrainBuilder {
+    state: MockState,
+}
+
+impl MockBrainBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fail the next call to `endpoint` once with `code`, then succeed
+    pub fn with_fail_once(self, endpoint: Endpoint, code: Code) -> Self {
+        self.with_fail_n_times(endpoint, 1, code)
+    }
+
+    /// Fail the next `n` calls to `endpoint` with `code`, then succeed
+    pub fn with_fail_n_times(mut self, endpoint: Endpoint, n: u32, code: Code) -> Self {
+        match endpoint {
+            Endpoint::ProcessVision => {
+                self.state.vision.remaining_failures = n;
+                self.state.vision.fail_code = code;
+            }
+            Endpoint::QueryMemory => {
+                self.state.memory.remaining_failures = n;
+                self.state.memory.fail_code = code;
+            }
+        }
+        self
+    }
+
+    /// Inject `latency` before every response (success or failure) from `endpoint`
+    pub fn with_latency(mut self, endpoint: Endpoint, latency: Duration) -> Self {
+        match endpoint {
+            Endpoint::ProcessVision => self.state.vision.latency = latency,
+            Endpoint::QueryMemory => self.state.memory.latency = latency,
+        }
+        self
+    }
+
+    /// Canned response returned by `process_vision` once its fault script is exhausted
+    pub fn with_vision_response(mut self, response: VisionResponse) -> Self {
+        self.state.vision.response = response;
+        self
+    }
+
+    /// Canned response returned by `query_memory` once its fault script is exhausted
+    pub fn with_memory_response(mut self, response: MemoryResponse) -> Self {
+        self.state.memory.response = response;
+        self
+    }
+
+    /// Spin up the mock behind an in-memory channel and return a connected handle
+    pub async fn serve(self) -> MockBrain {
+        let state = Arc::new(Mutex::new(self.state));
+        let service = MockBrainService { state: Arc::clone(&state) };
+
+        let (client_io, server_io) = tokio::io::duplex(4096);
+
+        let server = tokio::spawn(async move {
+            Server::builder()
+                .add_service(BrainServer::new(service))
+                .serve_with_incoming(tokio_stream::once(Ok::<_, std::io::Error>(server_io)))
+                .await
+                .expect("in-process mock Brain server failed");
+        });
+
+        let mut client_io = Some(client_io);
+        let channel = TransportEndpoint::try_from("http://[::]:50051")
+            .expect("static in-process endpoint is always valid")
+            .connect_with_connector(tower::service_fn(move |_: Uri| {
+                let client_io = client_io.take();
+                async move {
+                    client_io.ok_or_else(|| {
+                        std::io::Error::new(std::io::ErrorKind::Other, "mock Brain connector reused")
+                    })
+                }
+            }))
+            .await
+            .expect("connecting to the in-process mock Brain never touches the network");
+
+        MockBrain {
+            channel,
+            state,
+            server,
+        }
+    }
+}
+
+/// A running in-process mock Brain, holding the shared call log so tests can
+/// assert exact attempt counts after driving a `ChimeraClient` against it
+pub struct MockBrain {
+    channel: Channel,
+    state: Arc<Mutex<MockState>>,
+    server: tokio::task::JoinHandle<()>,
+}
+
+impl MockBrain {
+    /// A channel connected to this mock, to hand to `ChimeraClient::from_channel`
+    pub fn channel(&self) -> Channel {
+        self.channel.clone()
+    }
+
+    /// Every RPC received so far, in call order
+    pub async fn call_log(&self) -> Vec<CallKind> {
+        self.state.lock().await.call_log.clone()
+    }
+}
+
+impl Drop for MockBrain {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+struct MockBrainService {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[async_trait]
+impl Brain for MockBrainService {
+    async fn process_vision(
+        &self,
+        _request: Request<ProcessVisionRequest>,
+    ) -> Result<Response<VisionResponse>, Status> {
+        let mut state = self.state.lock().await;
+        state.call_log.push(CallKind::ProcessVision);
+        let latency = state.vision.latency;
+        let outcome = state.vision.take_failure();
+        let response = state.vision.response.clone();
+        drop(state);
+
+        if !latency.is_zero() {
+            tokio::time::sleep(latency).await;
+        }
+
+        match outcome {
+            Some(code) => Err(Status::new(code, "mock Brain: scripted failure")),
+            None => Ok(Response::new(response)),
+        }
+    }
+
+    async fn query_memory(
+        &self,
+        _request: Request<QueryMemoryRequest>,
+    ) -> Result<Response<MemoryResponse>, Status> {
+        let mut state = self.state.lock().await;
+        state.call_log.push(CallKind::QueryMemory);
+        let latency = state.memory.latency;
+        let outcome = state.memory.take_failure();
+        let response = state.memory.response.clone();
+        drop(state);
+
+        if !latency.is_zero() {
+            tokio::time::sleep(latency).await;
+        }
+
+        match outcome {
+            Some(code) => Err(Status::new(code, "mock Brain: scripted failure")),
+            None => Ok(Response::new(response)),
+        }
+    }
+}